@@ -0,0 +1,4 @@
+pub mod helpers;
+pub mod numeric;
+pub mod random;
+pub mod sobol;
@@ -0,0 +1,242 @@
+use ::common::numeric::Numeric;
+use ::errors::PricingError;
+
+pub fn to_fixed(number: f64, places: i32) -> f64 {
+    let base: f64 = 10.0;
+    let power: f64 = places as f64;
+    let divisor: f64 = base.powf(power);
+    return ((number * divisor).round()) / divisor
+}
+
+// Coverage levels offered by the program, 70% to 95% in 5% steps
+pub const COVERAGE_LEVELS: [f64; 6] = [0.70, 0.75, 0.80, 0.85, 0.90, 0.95];
+
+// Ladder priced when the caller doesn't pass one
+pub const DEFAULT_COVERAGE_LEVELS: [f64; 4] = [0.80, 0.85, 0.90, 0.95];
+
+pub fn default_coverage_levels() -> Vec<f64> {
+	DEFAULT_COVERAGE_LEVELS.to_vec()
+}
+
+pub fn validate_coverage_level(coverage_level: f64) -> Result<f64, PricingError> {
+	if COVERAGE_LEVELS.iter().any(|level| (level - coverage_level).abs() < 1e-9) {
+		Ok(coverage_level)
+	} else {
+		Err(PricingError::InvalidCoverageLevel(coverage_level))
+	}
+}
+
+pub fn ensure_finite(value: f64, field: &'static str) -> Result<f64, PricingError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(PricingError::NonFiniteResult(field))
+    }
+}
+
+pub fn validate_expected_yield(expected_yield: f64, standard_deviation: f64) -> Result<(), PricingError> {
+    if expected_yield.is_nan() || expected_yield <= 0.0 {
+        return Err(PricingError::InvalidExpectedYield(expected_yield))
+    }
+
+    if standard_deviation.is_nan() || standard_deviation < 0.0 {
+        return Err(PricingError::InvalidSigma(standard_deviation))
+    }
+
+    return Ok(())
+}
+
+pub fn calculate_simulated_yield_factor<N: Numeric>(draw: f64, expected_yield: f64, standard_deviation: f64) -> Result<f64, PricingError> {
+    let x = (norms_inv(draw, 0.0, 1.0)? * standard_deviation) + expected_yield;
+    let y = N::from_f64(x).round_to(4) / N::from_f64(expected_yield);
+    return Ok(y.round_to(4).to_f64())
+}
+
+pub fn calculate_simulated_price<N: Numeric>(draw: f64, sigma: f64, expected_price: f64) -> Result<f64, PricingError> {
+    if sigma.is_nan() || sigma < 0.0 {
+        return Err(PricingError::InvalidSigma(sigma))
+    }
+
+    if !expected_price.is_finite() || expected_price <= 0.0 {
+        return Err(PricingError::InvalidExpectedPrice(expected_price))
+    }
+
+    let a = {
+        let x = norms_inv(draw, 0.0, 1.0)? * sigma + expected_price.ln();
+        N::from_f64(x).round_to(4)
+    };
+
+    let b = {
+        let x = N::from_f64(sigma) * N::from_f64(sigma) * N::from_f64(0.5);
+        x.round_to(4)
+    };
+
+    let c = {
+        let x = (a - b).to_f64().exp();
+        N::from_f64(x).round_to(4)
+    };
+
+    return Ok(c.to_f64())
+}
+
+// The announced price for a month that has settled, otherwise one simulated from the draw
+pub fn known_or_simulated_price<N: Numeric>(known: Option<f64>, draw: f64, sigma: f64, expected_price: f64) -> Result<f64, PricingError> {
+    match known {
+        Some(price) => Ok(price),
+        None => calculate_simulated_price::<N>(draw, sigma, expected_price)
+    }
+}
+
+// Counts the leading months whose prices are all announced; a month is
+// either fully known or fully simulated, and only month one and two can be known
+pub fn count_known_months(month_one: &[Option<f64>], month_two: &[Option<f64>]) -> Result<usize, PricingError> {
+    let mut known = 0;
+
+    for (index, month) in [month_one, month_two].iter().enumerate() {
+        if month.iter().any(|price| price.is_some_and(|price| !price.is_finite())) {
+            return Err(PricingError::InvalidKnownPrices(format!("month {} has a price that is not a finite number", index + 1)))
+        }
+
+        if month.iter().all(|price| price.is_none()) {
+            continue
+        }
+
+        if month.iter().any(|price| price.is_none()) {
+            return Err(PricingError::InvalidKnownPrices(format!("month {} has only some of its prices announced", index + 1)))
+        }
+
+        if known != index {
+            return Err(PricingError::InvalidKnownPrices(format!("month {} is known but month {} is not", index + 1, index)))
+        }
+
+        known += 1;
+    }
+
+    return Ok(known)
+}
+
+#[allow(clippy::excessive_precision)]
+pub fn norms_inv(p: f64, mu: f64, sigma: f64) -> Result<f64, PricingError> {
+ 
+    // The tails are infinite, a draw of exactly 0 or 1 has no finite price
+    if !(p > 0.0 && p < 1.0) {
+        return Err(PricingError::InvalidProbability(p))
+    }
+
+    if sigma.is_nan() || sigma < 0.0 {
+        return Err(PricingError::InvalidSigma(sigma))
+    }
+
+    if sigma == 0.0 {
+        return Ok(mu)
+    }
+
+    let mut r: f64;
+    let mut val: f64;
+
+    let q: f64 = p - 0.5;
+
+    if q.abs() <= 0.425 {
+        r = 0.180625 - q*q;
+        val = q * (((((((r * 2509.0809287301226727 +
+                          33430.575583588128105) * r + 67265.770927008700853) * r +
+                        45921.953931549871457) * r + 13731.693765509461125) * r +
+                      1971.5909503065514427) * r + 133.14166789178437745) * r +
+                    3.387132872796366608)
+               / (((((((r * 5226.495278852854561 +
+                        28729.085735721942674) * r + 39307.89580009271061) * r +
+                      21213.794301586595867) * r + 5394.1960214247511077) * r +
+                    687.1870074920579083) * r + 42.313330701600911252) * r + 1.0);
+    
+    } else {
+        
+        if q > 0.0 {
+            r = 1.0 - p;
+        } else {
+            r = p;
+        }
+
+        r = -r.ln();
+        r = r.sqrt();
+
+        if r <= 5.0 {
+            r += -1.6;
+            val = (((((((r * 7.7454501427834140764e-4 +
+                       0.0227238449892691845833) * r + 0.24178072517745061177) *
+                     r + 1.27045825245236838258) * r +
+                    3.64784832476320460504) * r + 5.7694972214606914055) *
+                  r + 4.6303378461565452959) * r +
+                 1.42343711074968357734)
+                / (((((((r *
+                         1.05075007164441684324e-9 + 5.475938084995344946e-4) *
+                        r + 0.0151986665636164571966) * r +
+                       0.14810397642748007459) * r + 0.68976733498510000455) *
+                     r + 1.6763848301838038494) * r +
+                    2.05319162663775882187) * r + 1.0);
+
+        } else {
+            r += -5.0;
+            val = (((((((r * 2.01033439929228813265e-7 +
+                       2.71155556874348757815e-5) * r +
+                      0.0012426609473880784386) * r + 0.026532189526576123093) *
+                    r + 0.29656057182850489123) * r +
+                   1.7848265399172913358) * r + 5.4637849111641143699) *
+                 r + 6.6579046435011037772)
+                / (((((((r *
+                         2.04426310338993978564e-15 + 1.4215117583164458887e-7) *
+                        r + 1.8463183175100546818e-5) * r +
+                       7.868691311456132591e-4) * r + 0.0148753612908506148525)
+                     * r + 0.13692988092273580531) * r +
+                    0.59983220655588793769) * r + 1.0);
+        }
+
+        if q < 0.0
+        {
+            val = -val;
+        }
+    }
+
+    return Ok(mu + sigma * val);
+
+}
+// Standard normal cumulative distribution, Hart's double precision approximation
+#[allow(clippy::excessive_precision)]
+pub fn norms_dist(x: f64) -> f64 {
+    let xabs = x.abs();
+
+    let c: f64 = if xabs > 37.0 {
+        0.0
+    } else {
+        let e = (-xabs * xabs / 2.0).exp();
+
+        if xabs < 7.07106781186547 {
+            let mut b = 3.52624965998911e-02 * xabs + 0.700383064443688;
+            b = b * xabs + 6.37396220353165;
+            b = b * xabs + 33.912866078383;
+            b = b * xabs + 112.079291497871;
+            b = b * xabs + 221.213596169931;
+            b = b * xabs + 220.206867912376;
+            let n = e * b;
+
+            b = 8.83883476483184e-02 * xabs + 1.75566716318264;
+            b = b * xabs + 16.064177579207;
+            b = b * xabs + 86.7807322029461;
+            b = b * xabs + 296.564248779674;
+            b = b * xabs + 637.333633378831;
+            b = b * xabs + 793.826512519948;
+            b = b * xabs + 440.413735824752;
+            n / b
+        } else {
+            let mut b = xabs + 0.65;
+            b = xabs + 4.0 / b;
+            b = xabs + 3.0 / b;
+            b = xabs + 2.0 / b;
+            b = xabs + 1.0 / b;
+            e / b / 2.506628274631
+        }
+    };
+
+    if x > 0.0 {
+        1.0 - c
+    } else { c }
+}
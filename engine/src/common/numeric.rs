@@ -0,0 +1,107 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Sub};
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use ::common::helpers::to_fixed;
+
+// Number type for the steps of the calculation that round. `f64` is the fast
+// default, `DecimalNumber` works in base 10 so 2.675 rounds up to 2.68
+pub trait Numeric: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> {
+	fn from_f64(value: f64) -> Self;
+
+	fn to_f64(self) -> f64;
+
+	// Rounds halves away from zero, like `f64::round`
+	fn round_to(self, places: u32) -> Self;
+}
+
+impl Numeric for f64 {
+	fn from_f64(value: f64) -> f64 {
+		value
+	}
+
+	fn to_f64(self) -> f64 {
+		self
+	}
+
+	fn round_to(self, places: u32) -> f64 {
+		to_fixed(self, places as i32)
+	}
+}
+
+// A decimal that carries "not a number" through the calculation like an f64
+// would, so overflow and non-finite inputs surface from `ensure_finite`
+#[derive(Copy, Clone, Debug)]
+pub struct DecimalNumber(Option<Decimal>);
+
+impl DecimalNumber {
+	fn combine(self, other: DecimalNumber, operation: fn(Decimal, Decimal) -> Option<Decimal>) -> DecimalNumber {
+		match (self.0, other.0) {
+			(Some(a), Some(b)) => DecimalNumber(operation(a, b)),
+			_ => DecimalNumber(None)
+		}
+	}
+}
+
+impl Numeric for DecimalNumber {
+	fn from_f64(value: f64) -> DecimalNumber {
+		DecimalNumber(Decimal::from_f64(value))
+	}
+
+	fn to_f64(self) -> f64 {
+		self.0.and_then(|value| value.to_f64()).unwrap_or(f64::NAN)
+	}
+
+	fn round_to(self, places: u32) -> DecimalNumber {
+		DecimalNumber(self.0.map(|value| value.round_dp_with_strategy(places, RoundingStrategy::MidpointAwayFromZero)))
+	}
+}
+
+impl PartialEq for DecimalNumber {
+	fn eq(&self, other: &DecimalNumber) -> bool {
+		self.partial_cmp(other) == Some(Ordering::Equal)
+	}
+}
+
+impl PartialOrd for DecimalNumber {
+	fn partial_cmp(&self, other: &DecimalNumber) -> Option<Ordering> {
+		match (self.0, other.0) {
+			(Some(a), Some(b)) => a.partial_cmp(&b),
+			_ => None
+		}
+	}
+}
+
+impl Add for DecimalNumber {
+	type Output = DecimalNumber;
+
+	fn add(self, other: DecimalNumber) -> DecimalNumber {
+		self.combine(other, Decimal::checked_add)
+	}
+}
+
+impl Sub for DecimalNumber {
+	type Output = DecimalNumber;
+
+	fn sub(self, other: DecimalNumber) -> DecimalNumber {
+		self.combine(other, Decimal::checked_sub)
+	}
+}
+
+impl Mul for DecimalNumber {
+	type Output = DecimalNumber;
+
+	fn mul(self, other: DecimalNumber) -> DecimalNumber {
+		self.combine(other, Decimal::checked_mul)
+	}
+}
+
+impl Div for DecimalNumber {
+	type Output = DecimalNumber;
+
+	fn div(self, other: DecimalNumber) -> DecimalNumber {
+		self.combine(other, Decimal::checked_div)
+	}
+}
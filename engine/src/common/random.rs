@@ -0,0 +1,201 @@
+use ::common::helpers::{norms_dist, norms_inv};
+use ::common::sobol::SobolSequence;
+use ::errors::PricingError;
+
+// xoshiro256** seeded through splitmix64, so a seed gives the same draws on every platform
+pub struct DrawGenerator {
+	state: [u64; 4]
+}
+
+impl DrawGenerator {
+	pub fn new(seed: u64) -> DrawGenerator {
+		let mut x = seed;
+		let mut state = [0u64; 4];
+
+		for word in state.iter_mut() {
+			x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+			let mut z = x;
+			z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+			z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+			*word = z ^ (z >> 31);
+		}
+
+		return DrawGenerator { state }
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		let s = &mut self.state;
+		let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+		let t = s[1] << 17;
+
+		s[2] ^= s[0];
+		s[3] ^= s[1];
+		s[1] ^= s[2];
+		s[0] ^= s[3];
+		s[2] ^= t;
+		s[3] = s[3].rotate_left(45);
+
+		return result
+	}
+
+	// Uniform on the open interval (0, 1); the endpoints would map to infinite prices.
+	// 52 bits keep `n + 0.5` exact, with 53 the top value rounds up to 1
+	pub fn next_uniform(&mut self) -> f64 {
+		((self.next_u64() >> 12) as f64 + 0.5) / (1u64 << 52) as f64
+	}
+}
+
+// A draw built from `DIMENSION` independent uniforms, in field order
+pub trait UniformDraw: Sized {
+	const DIMENSION: usize;
+
+	fn from_uniforms(uniforms: &[f64]) -> Self;
+
+	// The mirrored draw, `1 - u` in every dimension
+	fn antithetic(&self) -> Self;
+}
+
+// Anything that can fill one draw's worth of uniforms on (0, 1)
+pub trait UniformSource {
+	fn fill(&mut self, uniforms: &mut [f64]);
+}
+
+impl UniformSource for DrawGenerator {
+	fn fill(&mut self, uniforms: &mut [f64]) {
+		for uniform in uniforms.iter_mut() {
+			*uniform = self.next_uniform();
+		}
+	}
+}
+
+// Largest draw set the engine will generate, which keeps a count from JS from
+// exhausting memory when the draws are allocated
+pub const MAX_DRAW_COUNT: usize = 1_000_000;
+
+pub fn validate_draw_count(count: usize) -> Result<usize, PricingError> {
+	if count > MAX_DRAW_COUNT {
+		return Err(PricingError::InvalidDrawCount(count))
+	}
+
+	return Ok(count)
+}
+
+pub fn draws_from_source<D: UniformDraw, S: UniformSource>(source: &mut S, count: usize, correlation: Option<&CorrelationMatrix>) -> Result<Vec<D>, PricingError> {
+	validate_draw_count(count)?;
+
+	let mut uniforms = vec![0.0; D::DIMENSION];
+
+	(0..count).map(|_| {
+		source.fill(&mut uniforms);
+
+		if let Some(matrix) = correlation {
+			matrix.correlate(&mut uniforms)?;
+		}
+
+		Ok(D::from_uniforms(&uniforms))
+	}).collect()
+}
+
+pub fn generate_draws<D: UniformDraw>(seed: u64, count: usize) -> Result<Vec<D>, PricingError> {
+	return draws_from_source(&mut DrawGenerator::new(seed), count, None);
+}
+
+// Lower triangular Cholesky factor of a correlation matrix across a draw's dimensions
+pub struct CorrelationMatrix {
+	lower: Vec<Vec<f64>>
+}
+
+impl CorrelationMatrix {
+	pub fn new(rows: &[Vec<f64>], dimension: usize) -> Result<CorrelationMatrix, PricingError> {
+		if rows.len() != dimension || rows.iter().any(|row| row.len() != dimension) {
+			return Err(PricingError::InvalidCorrelationMatrix(format!("expected a {} by {} matrix", dimension, dimension)))
+		}
+
+		for (i, row) in rows.iter().enumerate() {
+			if (row[i] - 1.0).abs() > 1e-9 {
+				return Err(PricingError::InvalidCorrelationMatrix(format!("diagonal entry {} is {}, not 1", i, row[i])))
+			}
+
+			for (j, value) in row.iter().enumerate().take(i) {
+				if !(-1.0..=1.0).contains(value) {
+					return Err(PricingError::InvalidCorrelationMatrix(format!("entry ({}, {}) is {}, outside -1 to 1", i, j, value)))
+				}
+
+				if (value - rows[j][i]).abs() > 1e-9 {
+					return Err(PricingError::InvalidCorrelationMatrix(format!("entries ({}, {}) and ({}, {}) differ", i, j, j, i)))
+				}
+			}
+		}
+
+		let mut lower = vec![vec![0.0; dimension]; dimension];
+
+		for i in 0..dimension {
+			for j in 0..=i {
+				let sum: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+
+				if i == j {
+					let pivot = rows[i][i] - sum;
+
+					if pivot.is_nan() || pivot <= 1e-12 {
+						return Err(PricingError::InvalidCorrelationMatrix(format!("the matrix is not positive definite, failed at row {}", i)))
+					}
+
+					lower[i][j] = pivot.sqrt();
+				} else {
+					lower[i][j] = (rows[i][j] - sum) / lower[j][j];
+				}
+			}
+		}
+
+		return Ok(CorrelationMatrix { lower })
+	}
+
+	// Maps independent uniforms to correlated ones through standard normals
+	pub fn correlate(&self, uniforms: &mut [f64]) -> Result<(), PricingError> {
+		let mut normals = Vec::with_capacity(uniforms.len());
+
+		for uniform in uniforms.iter() {
+			normals.push(norms_inv(*uniform, 0.0, 1.0)?);
+		}
+
+		for (i, uniform) in uniforms.iter_mut().enumerate() {
+			let z: f64 = (0..=i).map(|k| self.lower[i][k] * normals[k]).sum();
+			// Far tails round to 0 or 1, which `norms_inv` rejects
+			*uniform = norms_dist(z).clamp(f64::EPSILON / 2.0, 1.0 - f64::EPSILON / 2.0);
+		}
+
+		return Ok(())
+	}
+}
+
+pub fn generate_correlated_draws<D: UniformDraw>(seed: u64, count: usize, correlation: &[Vec<f64>]) -> Result<Vec<D>, PricingError> {
+	let matrix = CorrelationMatrix::new(correlation, D::DIMENSION)?;
+	return draws_from_source(&mut DrawGenerator::new(seed), count, Some(&matrix));
+}
+
+// Low-discrepancy draws; pass a seed to scramble them so repeated sets differ
+pub fn generate_sobol_draws<D: UniformDraw>(count: usize, scramble_seed: Option<u64>) -> Result<Vec<D>, PricingError> {
+	return draws_from_source(&mut SobolSequence::new(D::DIMENSION, scramble_seed)?, count, None);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rejects_a_matrix_that_is_not_positive_definite() {
+		let rows = vec![
+			vec![1.0, 0.9, -0.9],
+			vec![0.9, 1.0, 0.9],
+			vec![-0.9, 0.9, 1.0]
+		];
+
+		match CorrelationMatrix::new(&rows, 3) {
+			Err(PricingError::InvalidCorrelationMatrix(reason)) => assert!(reason.contains("not positive definite")),
+			other => panic!("expected a positive definiteness error, got {:?}", other.err())
+		}
+
+		let identity = vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 1.0]];
+		assert!(CorrelationMatrix::new(&identity, 3).is_ok());
+	}
+}
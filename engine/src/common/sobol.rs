@@ -0,0 +1,139 @@
+use ::common::random::{DrawGenerator, UniformSource};
+use ::errors::PricingError;
+
+// Enough for the component draw, three months of three commodities plus yield
+pub const SOBOL_MAX_DIMENSION: usize = 10;
+
+const BITS: usize = 32;
+
+// Joe and Kuo's primitive polynomials and initial direction numbers for
+// dimensions 2 to 10; the first dimension is the van der Corput sequence
+const DIRECTION_NUMBERS: [(u32, u32, &[u32]); SOBOL_MAX_DIMENSION - 1] = [
+	(1, 0, &[1]),
+	(2, 1, &[1, 3]),
+	(3, 1, &[1, 3, 1]),
+	(3, 2, &[1, 1, 1]),
+	(4, 1, &[1, 1, 3, 3]),
+	(4, 4, &[1, 3, 5, 13]),
+	(5, 2, &[1, 1, 5, 5, 17]),
+	(5, 4, &[1, 1, 5, 5, 5]),
+	(5, 7, &[1, 1, 7, 11, 19])
+];
+
+// Gray code Sobol points, optionally scrambled with a random digital shift
+pub struct SobolSequence {
+	directions: Vec<[u32; BITS]>,
+	point: Vec<u32>,
+	shift: Vec<u32>,
+	index: u32
+}
+
+impl SobolSequence {
+	pub fn new(dimension: usize, scramble_seed: Option<u64>) -> Result<SobolSequence, PricingError> {
+		if dimension > SOBOL_MAX_DIMENSION {
+			return Err(PricingError::InvalidSobolDimension(dimension))
+		}
+
+		let mut directions = Vec::with_capacity(dimension);
+
+		for d in 0..dimension {
+			let mut v = [0u32; BITS];
+
+			if d == 0 {
+				for (k, direction) in v.iter_mut().enumerate() {
+					*direction = 1 << (BITS - 1 - k);
+				}
+			} else {
+				let (s, a, m) = DIRECTION_NUMBERS[d - 1];
+				let s = s as usize;
+
+				for k in 0..BITS {
+					v[k] = if k < s {
+						m[k] << (BITS - 1 - k)
+					} else {
+						let mut x = v[k - s] ^ (v[k - s] >> s);
+
+						for i in 1..s {
+							if (a >> (s - 1 - i)) & 1 == 1 {
+								x ^= v[k - i];
+							}
+						}
+
+						x
+					};
+				}
+			}
+
+			directions.push(v);
+		}
+
+		let shift = match scramble_seed {
+			Some(seed) => {
+				let mut generator = DrawGenerator::new(seed);
+				(0..dimension).map(|_| (generator.next_u64() >> 32) as u32).collect()
+			},
+			None => vec![0; dimension]
+		};
+
+		return Ok(SobolSequence {
+			directions,
+			point: vec![0; dimension],
+			shift,
+			index: 0
+		})
+	}
+}
+
+impl UniformSource for SobolSequence {
+	// Starts from the second point, since the first sits on the origin
+	fn fill(&mut self, uniforms: &mut [f64]) {
+		self.index += 1;
+		let c = self.index.trailing_zeros() as usize;
+
+		for (d, uniform) in uniforms.iter_mut().enumerate() {
+			self.point[d] ^= self.directions[d][c];
+
+			// Centred in its cell so a shifted point can never land on 0
+			*uniform = ((self.point[d] ^ self.shift[d]) as f64 + 0.5) / (1u64 << BITS) as f64;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// The first points after the origin from Joe and Kuo's own generator
+	const PUBLISHED: [[f64; 4]; 9] = [
+		[0.5, 0.5, 0.5, 0.5],
+		[0.75, 0.25, 0.25, 0.25],
+		[0.25, 0.75, 0.75, 0.75],
+		[0.375, 0.375, 0.625, 0.875],
+		[0.875, 0.875, 0.125, 0.375],
+		[0.625, 0.125, 0.875, 0.625],
+		[0.125, 0.625, 0.375, 0.125],
+		[0.1875, 0.3125, 0.9375, 0.4375],
+		[0.6875, 0.8125, 0.4375, 0.9375]
+	];
+
+	#[test]
+	fn matches_the_published_points() {
+		let mut sequence = SobolSequence::new(4, None).unwrap();
+		let mut uniforms = [0.0; 4];
+
+		for point in PUBLISHED.iter() {
+			sequence.fill(&mut uniforms);
+
+			for (uniform, expected) in uniforms.iter().zip(point.iter()) {
+				// Less the half cell that keeps points off 0
+				assert_eq!(uniform - 0.5 / (1u64 << BITS) as f64, *expected);
+			}
+		}
+	}
+
+	#[test]
+	fn rejects_dimensions_without_direction_numbers() {
+		assert!(SobolSequence::new(SOBOL_MAX_DIMENSION, None).is_ok());
+		assert_eq!(SobolSequence::new(SOBOL_MAX_DIMENSION + 1, None).err(), Some(PricingError::InvalidSobolDimension(SOBOL_MAX_DIMENSION + 1)));
+	}
+}
@@ -0,0 +1,10 @@
+pub mod calculator;
+pub mod distribution;
+pub mod pricing_option;
+pub mod session;
+pub mod settlement;
+pub mod projection;
+pub mod convergence;
+pub mod trace;
+pub mod class_endorsement;
+pub mod component_endorsement;
\ No newline at end of file
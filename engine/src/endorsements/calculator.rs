@@ -0,0 +1,467 @@
+use ::common::helpers::{ensure_finite, validate_coverage_level};
+use ::common::numeric::{DecimalNumber, Numeric};
+use ::common::random::UniformDraw;
+use ::endorsements::distribution;
+use ::endorsements::pricing_option::PricingOption;
+use ::errors::PricingError;
+use ::types::base_types::{Arithmetic, Endorsement, EngineConfig, Quote, WorksheetStep};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub fn get_all_endorsements<P: PricingOption>(option: &P, quote: Quote, draws: &[P::Draw], coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Endorsement>, PricingError> {
+	let simulation_values = create_simulation_values(option, draws, config)?;
+	return calculate_all_endorsements(option, quote, &simulation_values, coverage_levels, config);
+}
+
+pub fn get_endorsement<P: PricingOption>(option: &P, quote: Quote, draws: &[P::Draw], coverage_level: f64, config: &EngineConfig) -> Result<Endorsement, PricingError> {
+	let simulation_values = create_simulation_values(option, draws, config)?;
+	return calculate_endorsement(option, quote, &simulation_values, coverage_level, config);
+}
+
+pub(crate) fn calculate_all_endorsements<P: PricingOption>(option: &P, quote: Quote, simulation_values: &[P::SimulationValue], coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Endorsement>, PricingError> {
+	// Reject the whole ladder before pricing any of it
+	for coverage_level in coverage_levels.iter() {
+		validate_coverage_level(*coverage_level)?;
+	}
+
+	let mut endorsements = Vec::with_capacity(coverage_levels.len());
+
+	for coverage_level in coverage_levels.iter() {
+		endorsements.push(calculate_endorsement(option, quote, simulation_values, *coverage_level, config)?);
+	}
+
+	return Ok(endorsements);
+}
+
+// With `config.antithetic` each draw's value is followed by its mirror's, twice as many values as draws.
+// Only the crate pairs them back up, with the config that built them, see `PricingSession`
+pub(crate) fn create_simulation_values<P: PricingOption>(option: &P, draws: &[P::Draw], config: &EngineConfig) -> Result<Vec<P::SimulationValue>, PricingError> {
+	match config.arithmetic {
+		Arithmetic::Float => simulation_values::<f64, P>(option, draws, config),
+		Arithmetic::Decimal => simulation_values::<DecimalNumber, P>(option, draws, config)
+	}
+}
+
+fn simulation_values<N: Numeric, P: PricingOption>(option: &P, draws: &[P::Draw], config: &EngineConfig) -> Result<Vec<P::SimulationValue>, PricingError> {
+	if draws.is_empty() {
+		return Err(PricingError::EmptyDraws)
+	}
+
+	option.validate()?;
+
+	#[cfg(not(feature = "parallel"))]
+	let draws = draws.iter();
+
+	#[cfg(feature = "parallel")]
+	let draws = draws.par_iter();
+
+	if !config.antithetic {
+		return draws.map(|draw| option.simulate::<N>(draw)).collect()
+	}
+
+	let pairs: Vec<(P::SimulationValue, P::SimulationValue)> = draws.map(|draw| {
+		Ok((option.simulate::<N>(draw)?, option.simulate::<N>(&draw.antithetic())?))
+	}).collect::<Result<_, PricingError>>()?;
+
+	let mut simulation_values = Vec::with_capacity(pairs.len() * 2);
+
+	for (value, mirror) in pairs {
+		simulation_values.push(value);
+		simulation_values.push(mirror);
+	}
+
+	return Ok(simulation_values)
+}
+
+pub(crate) fn calculate_endorsement<P: PricingOption>(option: &P, quote: Quote, simulation_values: &[P::SimulationValue], coverage_level: f64, config: &EngineConfig) -> Result<Endorsement, PricingError> {
+	match config.arithmetic {
+		Arithmetic::Float => price_endorsement::<f64, P>(option, quote, simulation_values, coverage_level, config),
+		Arithmetic::Decimal => price_endorsement::<DecimalNumber, P>(option, quote, simulation_values, coverage_level, config)
+	}
+}
+
+fn price_endorsement<N: Numeric, P: PricingOption>(option: &P, quote: Quote, simulation_values: &[P::SimulationValue], coverage_level: f64, config: &EngineConfig) -> Result<Endorsement, PricingError> {
+	validate_coverage_level(coverage_level)?;
+
+	// Inputs
+    let _lf: f64 = option.loading_factor();
+    let _dp: f64 = quote.dp;
+    let _protection: f64 = quote.protection;
+
+    // Weighted Prices
+    let _wp: f64 = option.expected_weighted_price::<N>(&quote);
+
+    // Expected Revenue Amount
+    let _era: f64 = expected_revenue_amount::<N, P>(option, &quote);
+
+    // Revenue Guarantee
+    let rg: f64 = whole_dollars::<N>(_era, coverage_level);
+
+    // Coverage Price
+    let cp: f64 = (N::from_f64(_wp) * N::from_f64(coverage_level)).to_f64();
+
+    // Loss Distribution
+    let _ld = match config.loss_distribution {
+        Some(ref settings) => {
+            let revenues: Vec<f64> = simulation_values.iter().map(|element| simulated_revenue_amount::<N, P>(option, element, &quote)).collect::<Result<_, PricingError>>()?;
+            Some(distribution::loss_distribution(&revenues, rg, _protection, settings)?)
+        },
+        None => None
+    };
+
+    #[cfg(not(feature = "parallel"))]
+    let simulation_values = simulation_values.iter();
+
+    #[cfg(feature = "parallel")]
+    let simulation_values = simulation_values.par_iter();
+
+    // Simulation Values
+    let _sl: Vec<f64> = simulation_values.map(|element| simulated_loss::<N, P>(option, element, &quote, rg)).collect::<Result<_, PricingError>>()?;
+
+    // Antithetic Pairs, each draw's loss averaged with its mirror's
+    let _sl: Vec<f64> = if config.antithetic {
+        _sl.chunks(2).map(|pair| pair.iter().fold(0.0, |acc, x| acc + x) / pair.len() as f64).collect()
+    } else { _sl };
+
+    // Average Simulated Loss, summed in draw order so both paths agree exactly
+    let asl: f64 = {
+        let total = _sl.iter().fold(0.0, |acc, x| acc + x);
+        ensure_finite(total / _sl.len() as f64, "averageSimulatedLoss")?
+    };
+
+    // Simulated Loss Standard Deviation
+    let slsd: f64 = {
+        let n = _sl.len() as f64;
+        let total = _sl.iter().fold(0.0, |acc, x| acc + (x - asl).powi(2));
+
+        if _sl.len() > 1 {
+            (total / (n - 1.0)).sqrt()
+        } else { 0.0 }
+    };
+
+    // Simulated Loss Standard Error
+    let slse: f64 = slsd / (_sl.len() as f64).sqrt();
+
+    // Premium Floor
+    let pf: f64 = (_dp * 0.02) / 100.0;
+
+    // Simulated Loss Average
+    let sla = if pf > asl {
+        pf
+    } else { asl };
+
+    // Preliminary Total Premium
+    let ptp: f64 = whole_dollars::<N>(sla, _protection);
+
+    // Total Premium Amount
+    let tpa: f64 = whole_dollars::<N>(ptp, _lf);
+
+    // Liability
+    let _lby = rg * _protection;
+
+    // Subsidy Schedule
+    let schedule = config.subsidy_schedule()?;
+
+    // Subsidy Percent
+    let sp: f64 = schedule.subsidy_percent(coverage_level)?;
+
+    // Beginning or Veteran Farmer Subsidy Points
+    let esp: f64 = schedule.enhanced_subsidy_points(&quote)?;
+
+    // Base Subsidy Amount
+    let bsa: f64 = whole_dollars::<N>(tpa, sp);
+
+    // Subsidy Amount
+    let sa: f64 = {
+        let share = N::from_f64(sp) + N::from_f64(esp);
+        let share = if share > N::from_f64(1.0) { N::from_f64(1.0) } else { share };
+        whole_dollars::<N>(tpa, share.to_f64())
+    };
+
+    // Enhanced Subsidy Amount
+    let esa: f64 = sa - bsa;
+
+    // Producer Premium Amount
+    let ppa: f64 = {
+        let x: f64 = (N::from_f64(tpa) - N::from_f64(sa)).round_to(0).to_f64();
+        if x > 1.0 { x }
+        else { 1.0 }
+    };
+
+    // Expected Indemnity, from the average loss before the premium floor and loading factor
+    let ei: f64 = whole_dollars::<N>(asl, _protection);
+
+    // Expected Loss Ratio, zero when the premium rounds to nothing
+    let elr: f64 = if tpa > 0.0 {
+        ei / tpa
+    } else { 0.0 };
+
+    // Expected Net Benefit
+    let enb: f64 = ei - ppa;
+
+    // Premium Confidence Half Widths, 1.96 standard errors scaled like the premium,
+    // none when the floor sets the premium and the simulation doesn't move it
+    let gphw: f64 = if pf > asl {
+        0.0
+    } else { 1.96 * slse * _protection * _lf };
+    let nphw: f64 = gphw * (1.0 - (sp + esp).min(1.0));
+
+    // Worksheet
+    let _ws: Option<Vec<WorksheetStep>> = if config.explain {
+        Some(vec![
+            WorksheetStep::new("weightedExpectedPrice", _wp, "none"),
+            WorksheetStep::new("expectedRevenueAmount", _era, "whole dollar"),
+            WorksheetStep::new("revenueGuarantee", rg, "whole dollar"),
+            WorksheetStep::new("coveragePrice", cp, "none"),
+            WorksheetStep::new("averageSimulatedLoss", asl, "none"),
+            WorksheetStep::new("premiumFloor", pf, "none"),
+            WorksheetStep::new("simulatedLossAverage", sla, "none"),
+            WorksheetStep::new("preliminaryTotalPremium", ptp, "whole dollar"),
+            WorksheetStep::new("loadingFactor", _lf, "none"),
+            WorksheetStep::new("totalPremiumAmount", tpa, "whole dollar"),
+            WorksheetStep::new("subsidyPercent", sp, "none"),
+            WorksheetStep::new("enhancedSubsidyPoints", esp, "none"),
+            WorksheetStep::new("subsidyAmount", sa, "whole dollar"),
+            WorksheetStep::new("producerPremiumAmount", ppa, "whole dollar, at least 1")
+        ])
+    } else { None };
+
+    // Covered Milk, cwt
+    let cm: f64 = (_dp * _protection) / 100.0;
+
+    // Premiums per cwt of Covered Milk
+    let tppc: f64 = per_cwt(tpa, cm);
+    let sppc: f64 = per_cwt(sa, cm);
+    let pppc: f64 = per_cwt(ppa, cm);
+
+    // Dollar Premiums, before scaling
+    let tpd: f64 = tpa;
+    let sd: f64 = sa;
+    let ppd: f64 = ppa;
+
+    // Scale Premiums
+    let ppa: f64 = ppa / 10000.0;
+    let tpa: f64 = tpa / 10000.0;
+    let sa: f64 = sa / 10000.0;
+    let bsa: f64 = bsa / 10000.0;
+    let esa: f64 = esa / 10000.0;
+    let gphw: f64 = gphw / 10000.0;
+    let nphw: f64 = nphw / 10000.0;
+
+    // Loss Spread, in the gross premium's units
+    let slsd: f64 = (slsd * _protection * _lf) / 10000.0;
+    let slse: f64 = (slse * _protection * _lf) / 10000.0;
+
+    return Ok(Endorsement {
+        netPremium: ensure_finite(ppa, "netPremium")?,
+        subsidy: ensure_finite(sa, "subsidy")?,
+        baseSubsidy: ensure_finite(bsa, "baseSubsidy")?,
+        enhancedSubsidy: ensure_finite(esa, "enhancedSubsidy")?,
+        grossPremium: ensure_finite(tpa, "grossPremium")?,
+        protectedPrice: ensure_finite(cp, "protectedPrice")?,
+        level: coverage_level,
+        revenueGuarantee: ensure_finite(rg, "revenueGuarantee")?,
+        liability: ensure_finite(_lby, "liability")?,
+        lossStandardDeviation: ensure_finite(slsd, "lossStandardDeviation")?,
+        lossStandardError: ensure_finite(slse, "lossStandardError")?,
+        grossPremiumLower: ensure_finite((tpa - gphw).max(0.0), "grossPremiumLower")?,
+        grossPremiumUpper: ensure_finite(tpa + gphw, "grossPremiumUpper")?,
+        netPremiumLower: ensure_finite((ppa - nphw).max(0.0), "netPremiumLower")?,
+        netPremiumUpper: ensure_finite(ppa + nphw, "netPremiumUpper")?,
+        coveredMilk: ensure_finite(cm, "coveredMilk")?,
+        grossPremiumDollars: ensure_finite(tpd, "grossPremiumDollars")?,
+        subsidyDollars: ensure_finite(sd, "subsidyDollars")?,
+        producerPremiumDollars: ensure_finite(ppd, "producerPremiumDollars")?,
+        grossPremiumPerCwt: ensure_finite(tppc, "grossPremiumPerCwt")?,
+        subsidyPerCwt: ensure_finite(sppc, "subsidyPerCwt")?,
+        producerPremiumPerCwt: ensure_finite(pppc, "producerPremiumPerCwt")?,
+        expectedIndemnity: ensure_finite(ei, "expectedIndemnity")?,
+        expectedLossRatio: ensure_finite(elr, "expectedLossRatio")?,
+        expectedNetBenefit: ensure_finite(enb, "expectedNetBenefit")?,
+        lossDistribution: _ld,
+        worksheet: _ws
+    })
+}
+
+// Dollars per cwt of covered milk, zero when no milk is covered
+pub fn per_cwt(amount: f64, covered_milk: f64) -> f64 {
+    if covered_milk > 0.0 {
+        amount / covered_milk
+    } else { 0.0 }
+}
+
+// Product of an amount and a rate, rounded to whole dollars
+pub fn whole_dollars<N: Numeric>(amount: f64, rate: f64) -> f64 {
+    (N::from_f64(amount) * N::from_f64(rate)).round_to(0).to_f64()
+}
+
+pub fn expected_revenue_amount<N: Numeric, P: PricingOption>(option: &P, quote: &Quote) -> f64 {
+    let x = (N::from_f64(option.expected_weighted_price::<N>(quote)) * N::from_f64(quote.dp)) / N::from_f64(100.00);
+    x.round_to(0).to_f64()
+}
+
+// Revenue for one draw, or for the announced prices when settling
+pub fn simulated_revenue_amount<N: Numeric, P: PricingOption>(option: &P, value: &P::SimulationValue, quote: &Quote) -> Result<f64, PricingError> {
+    // Weighted Simulated Price
+    let wsp = N::from_f64(option.simulated_weighted_price::<N>(value, quote));
+
+    // Simulated Yield
+    let sy = N::from_f64(quote.dp) * N::from_f64(option.simulated_yield_factor(value));
+
+    let x = ((wsp * sy) / N::from_f64(100.0)).round_to(0).to_f64();
+    ensure_finite(x, "simulatedRevenue")
+}
+
+// Shortfall of one draw's revenue against the guarantee, zero when there is none.
+// A revenue that is not a number is an error rather than a draw without a loss
+pub fn simulated_loss<N: Numeric, P: PricingOption>(option: &P, value: &P::SimulationValue, quote: &Quote, revenue_guarantee: f64) -> Result<f64, PricingError> {
+    // Simulated Revenue Amount
+    let sra: f64 = simulated_revenue_amount::<N, P>(option, value, quote)?;
+
+    // Calculated Loss
+    let cl = revenue_guarantee - sra;
+
+    // Simulated Loss
+    if cl > 0.0 {
+        Ok(cl)
+    } else { Ok(0.0) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ::common::helpers::DEFAULT_COVERAGE_LEVELS;
+	use ::common::random::generate_draws;
+	use ::endorsements::class_endorsement::ClassOption;
+	use ::types::class_types::{DailyPrice, Draw, SimulationValue};
+
+	fn option() -> ClassOption {
+		let prices: DailyPrice = serde_json::from_str(r#"{
+			"expectedClassThreePrice": 17.25, "expectedClassFourPrice": 16.10, "loadingFactor": 1.0,
+			"monthOneClassThreeSigma": 0.312, "monthOneExpectedClassThreePrice": 17.0,
+			"monthTwoClassThreeSigma": 0.315, "monthTwoExpectedClassThreePrice": 17.3,
+			"monthThreeClassThreeSigma": 0.318, "monthThreeExpectedClassThreePrice": 17.45,
+			"monthOneClassFourSigma": 0.311, "monthOneExpectedClassFourPrice": 16.0,
+			"monthTwoClassFourSigma": 0.314, "monthTwoExpectedClassFourPrice": 16.1,
+			"monthThreeClassFourSigma": 0.317, "monthThreeExpectedClassFourPrice": 16.2,
+			"expectedYield": 2000.0, "expectedYieldStandardDeviation": 40.0
+		}"#).unwrap();
+
+		return ClassOption { prices }
+	}
+
+	fn quote() -> Quote {
+		serde_json::from_str(r#"{"classWeight": 0.5, "butterfat": 3.9, "protein": 3.1, "production": 1000000.0, "protection": 1.0}"#).unwrap()
+	}
+
+	fn draws() -> Vec<Draw> {
+		generate_draws(7, 4000).unwrap()
+	}
+
+	#[test]
+	fn endorsements_match_serially_simulated_draws() {
+		let option = option();
+		let draws = draws();
+		let config = EngineConfig::default();
+
+		let values = create_simulation_values(&option, &draws, &config).unwrap();
+		let serial: Vec<SimulationValue> = draws.iter().map(|draw| option.simulate::<f64>(draw).unwrap()).collect();
+		assert_eq!(serde_json::to_string(&values).unwrap(), serde_json::to_string(&serial).unwrap());
+
+		let endorsements = get_all_endorsements(&option, quote(), &draws, &DEFAULT_COVERAGE_LEVELS, &config).unwrap();
+		let expected = calculate_all_endorsements(&option, quote(), &serial, &DEFAULT_COVERAGE_LEVELS, &config).unwrap();
+		assert_eq!(serde_json::to_string(&endorsements).unwrap(), serde_json::to_string(&expected).unwrap());
+	}
+
+	#[test]
+	fn decimal_rounds_halves_away_from_zero() {
+		assert_eq!(1.005.round_to(2), 1.0);
+		assert_eq!(DecimalNumber::from_f64(1.005).round_to(2).to_f64(), 1.01);
+
+		// 1285 * 0.7 is 899.4999999999999 in f64
+		assert_eq!(whole_dollars::<f64>(1285.0, 0.7), 899.0);
+		assert_eq!(whole_dollars::<DecimalNumber>(1285.0, 0.7), 900.0);
+	}
+
+	#[test]
+	fn decimal_matches_float_away_from_halves() {
+		let option = option();
+		let draws = draws();
+		let decimal = EngineConfig { arithmetic: Arithmetic::Decimal, ..EngineConfig::default() };
+
+		let float = get_all_endorsements(&option, quote(), &draws, &DEFAULT_COVERAGE_LEVELS, &EngineConfig::default()).unwrap();
+		let exact = get_all_endorsements(&option, quote(), &draws, &DEFAULT_COVERAGE_LEVELS, &decimal).unwrap();
+
+		for (a, b) in float.iter().zip(exact.iter()) {
+			assert_eq!(a.revenueGuarantee, b.revenueGuarantee);
+			assert_eq!(a.grossPremiumDollars, b.grossPremiumDollars);
+			assert_eq!(a.producerPremiumDollars, b.producerPremiumDollars);
+		}
+	}
+
+	#[test]
+	fn rejects_an_expected_price_that_is_not_positive() {
+		let draws = draws();
+
+		for price in [-17.0, 0.0, f64::NAN].iter() {
+			let mut option = option();
+			option.prices.m1ec3p = *price;
+
+			let result = get_all_endorsements(&option, quote(), &draws, &DEFAULT_COVERAGE_LEVELS, &EngineConfig::default());
+			assert_eq!(result.err().map(|err| err.code()), Some("ERR_INVALID_EXPECTED_PRICE"));
+		}
+	}
+
+	#[test]
+	fn floored_premiums_have_no_confidence_interval() {
+		let option = option();
+		let draws = draws();
+		let config = EngineConfig { explain: true, ..EngineConfig::default() };
+
+		let endorsement = get_endorsement(&option, quote(), &draws, 0.70, &config).unwrap();
+		let step = |name: &str| endorsement.worksheet.as_ref().unwrap().iter().find(|step| step.step == name).unwrap().value;
+		assert!(step("premiumFloor") > step("averageSimulatedLoss"));
+
+		assert_eq!(endorsement.grossPremiumLower, endorsement.grossPremium);
+		assert_eq!(endorsement.grossPremiumUpper, endorsement.grossPremium);
+		assert_eq!(endorsement.netPremiumLower, endorsement.netPremium);
+		assert_eq!(endorsement.netPremiumUpper, endorsement.netPremium);
+	}
+
+	#[test]
+	fn enhanced_subsidy_is_capped_at_the_whole_premium() {
+		let option = option();
+		let draws = draws();
+
+		let mut config = EngineConfig::default();
+		config.subsidy_schedules.schedules[0].rates[5].percent = 0.95;
+
+		let mut quote = quote();
+		quote.bfr = true;
+		quote.vfr = true;
+
+		let endorsement = get_endorsement(&option, quote, &draws, 0.95, &config).unwrap();
+		let base = whole_dollars::<f64>(endorsement.grossPremiumDollars, 0.95);
+
+		assert_eq!(endorsement.subsidyDollars, endorsement.grossPremiumDollars);
+		assert_eq!(endorsement.baseSubsidy, base / 10000.0);
+		assert_eq!(endorsement.enhancedSubsidy, (endorsement.grossPremiumDollars - base) / 10000.0);
+		assert_eq!(endorsement.producerPremiumDollars, 1.0);
+	}
+
+	#[cfg(feature = "parallel")]
+	#[test]
+	fn parallel_endorsements_match_a_single_thread() {
+		let option = option();
+		let draws = draws();
+		let config = EngineConfig::default();
+
+		let parallel = get_all_endorsements(&option, quote(), &draws, &DEFAULT_COVERAGE_LEVELS, &config).unwrap();
+		let serial = ::rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap().install(|| {
+			get_all_endorsements(&option, quote(), &draws, &DEFAULT_COVERAGE_LEVELS, &config).unwrap()
+		});
+
+		assert_eq!(serde_json::to_string(&parallel).unwrap(), serde_json::to_string(&serial).unwrap());
+	}
+}
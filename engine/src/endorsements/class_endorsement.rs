@@ -0,0 +1,138 @@
+use std::io::Write;
+
+use ::common::helpers::{calculate_simulated_price, calculate_simulated_yield_factor, count_known_months, known_or_simulated_price, validate_expected_yield};
+use ::common::numeric::Numeric;
+use ::endorsements::calculator;
+use ::endorsements::convergence;
+use ::endorsements::projection;
+use ::endorsements::settlement;
+use ::endorsements::trace;
+use ::endorsements::pricing_option::{PricingOption, TraceFields};
+use ::errors::PricingError;
+use ::types::class_types::{ActualPrice, Draw, DailyPrice, SimulationValue};
+use ::types::base_types::{ConvergencePoint, ConvergenceSettings, Endorsement, EngineConfig, Projection, Quote, Settlement, TraceFormat};
+
+pub struct ClassOption {
+	pub prices: DailyPrice
+}
+
+impl PricingOption for ClassOption {
+	type Draw = Draw;
+	type SimulationValue = SimulationValue;
+	type ActualPrice = ActualPrice;
+
+	fn loading_factor(&self) -> f64 {
+		self.prices.lf
+	}
+
+	fn validate(&self) -> Result<(), PricingError> {
+		self.known_months()?;
+		validate_expected_yield(self.prices.ey, self.prices.eysd)
+	}
+
+	fn known_months(&self) -> Result<usize, PricingError> {
+		let prices = &self.prices;
+		count_known_months(&[prices.m1c3p, prices.m1c4p], &[prices.m2c3p, prices.m2c4p])
+	}
+
+	fn expected_weighted_price<N: Numeric>(&self, quote: &Quote) -> f64 {
+		let cw = N::from_f64(quote.cw);
+		let wc3p = N::from_f64(self.prices.ec3p) * cw;
+		let wc4p = N::from_f64(self.prices.ec4p) * (N::from_f64(1.0) - cw);
+		(wc3p + wc4p).to_f64()
+	}
+
+	fn simulate<N: Numeric>(&self, draw: &Draw) -> Result<SimulationValue, PricingError> {
+		let monthly = self.monthly_prices::<N>(draw)?;
+		return self.settlement_value::<N>(&monthly)
+	}
+
+	fn trace<N: Numeric>(&self, draw: &Draw) -> Result<(SimulationValue, TraceFields), PricingError> {
+		let monthly = self.monthly_prices::<N>(draw)?;
+		let value = self.settlement_value::<N>(&monthly)?;
+
+		let fields = vec![
+			("monthOneClassThreePrice", monthly.m1c3p),
+			("monthTwoClassThreePrice", monthly.m2c3p),
+			("monthThreeClassThreePrice", monthly.m3c3p),
+			("monthOneClassFourPrice", monthly.m1c4p),
+			("monthTwoClassFourPrice", monthly.m2c4p),
+			("monthThreeClassFourPrice", monthly.m3c4p),
+			("simulatedClassThreePrice", value.sc3p),
+			("simulatedClassFourPrice", value.sc4p),
+			("simulatedYieldAdjustmentFactor", value.syaf)
+		];
+
+		return Ok((value, fields))
+	}
+
+	fn simulated_weighted_price<N: Numeric>(&self, value: &SimulationValue, quote: &Quote) -> f64 {
+		let cw = N::from_f64(quote.cw);
+		let wc3p = N::from_f64(value.sc3p) * cw;
+		let wc4p = N::from_f64(value.sc4p) * (N::from_f64(1.0) - cw);
+		(wc3p + wc4p).to_f64()
+	}
+
+	fn simulated_yield_factor(&self, value: &SimulationValue) -> f64 {
+		value.syaf
+	}
+
+	fn settlement_value<N: Numeric>(&self, actual: &ActualPrice) -> Result<SimulationValue, PricingError> {
+		let sc3p: f64 = {
+			let x = (N::from_f64(actual.m1c3p) + N::from_f64(actual.m2c3p) + N::from_f64(actual.m3c3p)) / N::from_f64(3.0);
+			x.round_to(2).to_f64()
+		};
+
+		let sc4p: f64 = {
+			let x = (N::from_f64(actual.m1c4p) + N::from_f64(actual.m2c4p) + N::from_f64(actual.m3c4p)) / N::from_f64(3.0);
+			x.round_to(2).to_f64()
+		};
+
+		return Ok(SimulationValue {
+			sc3p,
+			sc4p,
+			syaf: actual.yaf
+		})
+	}
+}
+
+impl ClassOption {
+	// Simulated monthly prices and yield for one draw, in the shape of the announced quarter
+	fn monthly_prices<N: Numeric>(&self, draw: &Draw) -> Result<ActualPrice, PricingError> {
+		let prices = &self.prices;
+
+		return Ok(ActualPrice {
+			m1c3p: known_or_simulated_price::<N>(prices.m1c3p, draw.m1c3pd, prices.m1c3s, prices.m1ec3p)?,
+			m2c3p: known_or_simulated_price::<N>(prices.m2c3p, draw.m2c3pd, prices.m2c3s, prices.m2ec3p)?,
+			m3c3p: calculate_simulated_price::<N>(draw.m3c3pd, prices.m3c3s, prices.m3ec3p)?,
+			m1c4p: known_or_simulated_price::<N>(prices.m1c4p, draw.m1c4pd, prices.m1c4s, prices.m1ec4p)?,
+			m2c4p: known_or_simulated_price::<N>(prices.m2c4p, draw.m2c4pd, prices.m2c4s, prices.m2ec4p)?,
+			m3c4p: calculate_simulated_price::<N>(draw.m3c4pd, prices.m3c4s, prices.m3ec4p)?,
+			yaf: calculate_simulated_yield_factor::<N>(draw.ydq, prices.ey, prices.eysd)?
+		})
+	}
+}
+
+pub fn get_all_endorsements(prices: DailyPrice, quote: Quote, draws: &[Draw], coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Endorsement>, PricingError> {
+	return calculator::get_all_endorsements(&ClassOption { prices }, quote, draws, coverage_levels, config);
+}
+
+pub fn get_endorsement(prices: DailyPrice, quote: Quote, draws: &[Draw], coverage_level: f64, config: &EngineConfig) -> Result<Endorsement, PricingError> {
+	return calculator::get_endorsement(&ClassOption { prices }, quote, draws, coverage_level, config);
+}
+
+pub fn get_settlements(prices: DailyPrice, quote: Quote, actual: &ActualPrice, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Settlement>, PricingError> {
+	return settlement::get_all_settlements(&ClassOption { prices }, quote, actual, coverage_levels, config);
+}
+
+pub fn get_projections(prices: DailyPrice, quote: Quote, draws: &[Draw], coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Projection>, PricingError> {
+	return projection::get_all_projections(&ClassOption { prices }, quote, draws, coverage_levels, config);
+}
+
+pub fn get_convergence(prices: DailyPrice, quote: Quote, coverage_level: f64, config: &EngineConfig, settings: &ConvergenceSettings) -> Result<Vec<ConvergencePoint>, PricingError> {
+	return convergence::compare_convergence(&ClassOption { prices }, quote, coverage_level, config, settings);
+}
+
+pub fn write_trace<W: Write + ?Sized>(prices: DailyPrice, quote: Quote, draws: &[Draw], coverage_level: f64, format: TraceFormat, config: &EngineConfig, writer: &mut W) -> Result<usize, PricingError> {
+	return trace::write_trace(&ClassOption { prices }, quote, draws, coverage_level, format, config, writer);
+}
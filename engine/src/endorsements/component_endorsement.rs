@@ -0,0 +1,236 @@
+use std::io::Write;
+
+use ::common::helpers::{calculate_simulated_price, calculate_simulated_yield_factor, count_known_months, known_or_simulated_price, validate_expected_yield};
+use ::common::numeric::{DecimalNumber, Numeric};
+use ::endorsements::calculator;
+use ::endorsements::convergence;
+use ::endorsements::projection;
+use ::endorsements::settlement;
+use ::endorsements::trace;
+use ::endorsements::pricing_option::{PricingOption, TraceFields};
+use ::errors::PricingError;
+use ::types::component_types::{ActualPrice, ComponentPrice, DailyPrice, Draw, PricingFactor, ProductPrice, SimulationValue};
+use ::types::base_types::{Arithmetic, ConvergencePoint, ConvergenceSettings, Endorsement, EngineConfig, Projection, Quote, Settlement, TraceFormat};
+
+pub struct ComponentOption {
+	pub prices: DailyPrice,
+	pub factors: PricingFactor
+}
+
+impl PricingOption for ComponentOption {
+	type Draw = Draw;
+	type SimulationValue = SimulationValue;
+	type ActualPrice = ActualPrice;
+
+	fn loading_factor(&self) -> f64 {
+		self.prices.lf
+	}
+
+	fn validate(&self) -> Result<(), PricingError> {
+		self.known_months()?;
+		validate_expected_yield(self.prices.ey, self.prices.eysd)
+	}
+
+	fn known_months(&self) -> Result<usize, PricingError> {
+		let prices = &self.prices;
+		count_known_months(&[prices.m1bp, prices.m1cp, prices.m1dwp], &[prices.m2bp, prices.m2cp, prices.m2dwp])
+	}
+
+	fn expected_weighted_price<N: Numeric>(&self, quote: &Quote) -> f64 {
+		let webr = N::from_f64(self.prices.ebfp) * N::from_f64(quote.dbt);
+		let wepr = N::from_f64(self.prices.epp) * N::from_f64(quote.dpt);
+		let weosr = N::from_f64(self.prices.eosp) * N::from_f64(5.7);
+		(webr + wepr + weosr).to_f64()
+	}
+
+	fn simulate<N: Numeric>(&self, draw: &Draw) -> Result<SimulationValue, PricingError> {
+		let (_, components, syaf) = self.monthly_prices::<N>(draw)?;
+
+		return Ok(SimulationValue {
+			sbfp: components.bfp,
+			sosp: components.osp,
+			spp: components.pp,
+			syaf
+		})
+	}
+
+	fn trace<N: Numeric>(&self, draw: &Draw) -> Result<(SimulationValue, TraceFields), PricingError> {
+		let (products, components, syaf) = self.monthly_prices::<N>(draw)?;
+
+		let value = SimulationValue {
+			sbfp: components.bfp,
+			sosp: components.osp,
+			spp: components.pp,
+			syaf
+		};
+
+		let fields = vec![
+			("monthOneButterPrice", products.m1bp),
+			("monthTwoButterPrice", products.m2bp),
+			("monthThreeButterPrice", products.m3bp),
+			("monthOneCheesePrice", products.m1cp),
+			("monthTwoCheesePrice", products.m2cp),
+			("monthThreeCheesePrice", products.m3cp),
+			("monthOneDryWheyPrice", products.m1dwp),
+			("monthTwoDryWheyPrice", products.m2dwp),
+			("monthThreeDryWheyPrice", products.m3dwp),
+			("monthOneButterfatPrice", components.m1bfp),
+			("monthTwoButterfatPrice", components.m2bfp),
+			("monthThreeButterfatPrice", components.m3bfp),
+			("monthOneProteinPrice", components.m1pp),
+			("monthTwoProteinPrice", components.m2pp),
+			("monthThreeProteinPrice", components.m3pp),
+			("monthOneOtherSolidsPrice", components.m1osp),
+			("monthTwoOtherSolidsPrice", components.m2osp),
+			("monthThreeOtherSolidsPrice", components.m3osp),
+			("simulatedButterfatPrice", value.sbfp),
+			("simulatedProteinPrice", value.spp),
+			("simulatedOtherSolidsPrice", value.sosp),
+			("simulatedYieldAdjustmentFactor", value.syaf)
+		];
+
+		return Ok((value, fields))
+	}
+
+	fn simulated_weighted_price<N: Numeric>(&self, value: &SimulationValue, quote: &Quote) -> f64 {
+		let x = N::from_f64(value.sbfp) * N::from_f64(quote.dbt);
+		let y = N::from_f64(value.spp) * N::from_f64(quote.dpt);
+		let z = N::from_f64(value.sosp) * N::from_f64(5.7);
+		(x + y + z).to_f64()
+	}
+
+	fn simulated_yield_factor(&self, value: &SimulationValue) -> f64 {
+		value.syaf
+	}
+
+	fn settlement_value<N: Numeric>(&self, actual: &ActualPrice) -> Result<SimulationValue, PricingError> {
+		return Ok(SimulationValue {
+			sbfp: quarterly_average::<N>(actual.m1bfp, actual.m2bfp, actual.m3bfp),
+			sosp: quarterly_average::<N>(actual.m1osp, actual.m2osp, actual.m3osp),
+			spp: quarterly_average::<N>(actual.m1pp, actual.m2pp, actual.m3pp),
+			syaf: actual.yaf
+		})
+	}
+}
+
+impl ComponentOption {
+	// Simulated product and component prices for each month, and the yield adjustment factor
+	fn monthly_prices<N: Numeric>(&self, draw: &Draw) -> Result<(ProductPrice, ComponentPrice, f64), PricingError> {
+		let prices = &self.prices;
+
+		let products = ProductPrice {
+			m1bp: known_or_simulated_price::<N>(prices.m1bp, draw.m1bpd, prices.m1bs, prices.m1ebp)?,
+			m2bp: known_or_simulated_price::<N>(prices.m2bp, draw.m2bpd, prices.m2bs, prices.m2ebp)?,
+			m3bp: calculate_simulated_price::<N>(draw.m3bpd, prices.m3bs, prices.m3ebp)?,
+			m1cp: known_or_simulated_price::<N>(prices.m1cp, draw.m1cpd, prices.m1cs, prices.m1ecp)?,
+			m2cp: known_or_simulated_price::<N>(prices.m2cp, draw.m2cpd, prices.m2cs, prices.m2ecp)?,
+			m3cp: calculate_simulated_price::<N>(draw.m3cpd, prices.m3cs, prices.m3ecp)?,
+			m1dwp: known_or_simulated_price::<N>(prices.m1dwp, draw.m1dwpd, prices.m1dws, prices.m1edwp)?,
+			m2dwp: known_or_simulated_price::<N>(prices.m2dwp, draw.m2dwpd, prices.m2dws, prices.m2edwp)?,
+			m3dwp: calculate_simulated_price::<N>(draw.m3dwpd, prices.m3dws, prices.m3edwp)?
+		};
+
+		let components = calculate_component_prices::<N>(&products, &self.factors);
+
+		//Simulated Yield Adjustment
+		let syaf = calculate_simulated_yield_factor::<N>(draw.ydq, prices.ey, prices.eysd)?;
+
+		return Ok((products, components, syaf))
+	}
+}
+
+// Federal order component prices for each month of the quarter, and their quarterly averages
+pub fn calculate_component_prices<N: Numeric>(products: &ProductPrice, factors: &PricingFactor) -> ComponentPrice {
+	//Butterfat
+	let m1bfp = butterfat_price::<N>(products.m1bp, factors);
+	let m2bfp = butterfat_price::<N>(products.m2bp, factors);
+	let m3bfp = butterfat_price::<N>(products.m3bp, factors);
+
+	//Other Solids
+	let m1osp = other_solids_price::<N>(products.m1dwp, factors);
+	let m2osp = other_solids_price::<N>(products.m2dwp, factors);
+	let m3osp = other_solids_price::<N>(products.m3dwp, factors);
+
+	//Protein
+	let m1pp = protein_price::<N>(products.m1cp, m1bfp, factors);
+	let m2pp = protein_price::<N>(products.m2cp, m2bfp, factors);
+	let m3pp = protein_price::<N>(products.m3cp, m3bfp, factors);
+
+	return ComponentPrice {
+		m1bfp,
+		m2bfp,
+		m3bfp,
+		m1pp,
+		m2pp,
+		m3pp,
+		m1osp,
+		m2osp,
+		m3osp,
+		bfp: quarterly_average::<N>(m1bfp, m2bfp, m3bfp),
+		pp: quarterly_average::<N>(m1pp, m2pp, m3pp),
+		osp: quarterly_average::<N>(m1osp, m2osp, m3osp)
+	}
+}
+
+fn butterfat_price<N: Numeric>(butter_price: f64, factors: &PricingFactor) -> f64 {
+	let x = (N::from_f64(butter_price) - N::from_f64(factors.bma)) * N::from_f64(factors.bmy);
+	x.round_to(4).to_f64()
+}
+
+fn other_solids_price<N: Numeric>(dry_whey_price: f64, factors: &PricingFactor) -> f64 {
+	let x = (N::from_f64(dry_whey_price) - N::from_f64(factors.dwma)) * N::from_f64(factors.dwmy);
+	x.round_to(4).to_f64()
+}
+
+fn protein_price<N: Numeric>(cheese_price: f64, butterfat_price: f64, factors: &PricingFactor) -> f64 {
+	let cheese = N::from_f64(cheese_price) - N::from_f64(factors.cma);
+
+	let a = cheese * N::from_f64(factors.cmyc);
+	let pc = a.round_to(4);
+
+	let b = cheese * N::from_f64(factors.cmyb);
+	let c = b.round_to(4);
+	let d = N::from_f64(butterfat_price) * N::from_f64(factors.brr);
+	let f = d.round_to(4);
+	let bc = (c - f) * N::from_f64(factors.btpr);
+
+	let x = pc + bc;
+	x.round_to(4).to_f64()
+}
+
+fn quarterly_average<N: Numeric>(m1: f64, m2: f64, m3: f64) -> f64 {
+	let x = (N::from_f64(m1) + N::from_f64(m2) + N::from_f64(m3)) / N::from_f64(3.0);
+	x.round_to(2).to_f64()
+}
+
+pub fn get_all_endorsements(prices: DailyPrice, quote: Quote, draws: &[Draw], factors: PricingFactor, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Endorsement>, PricingError> {
+	return calculator::get_all_endorsements(&ComponentOption { prices, factors }, quote, draws, coverage_levels, config);
+}
+
+pub fn get_endorsement(prices: DailyPrice, quote: Quote, draws: &[Draw], factors: PricingFactor, coverage_level: f64, config: &EngineConfig) -> Result<Endorsement, PricingError> {
+	return calculator::get_endorsement(&ComponentOption { prices, factors }, quote, draws, coverage_level, config);
+}
+
+pub fn get_settlements(prices: DailyPrice, quote: Quote, actual: &ActualPrice, factors: PricingFactor, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Settlement>, PricingError> {
+	return settlement::get_all_settlements(&ComponentOption { prices, factors }, quote, actual, coverage_levels, config);
+}
+
+pub fn get_projections(prices: DailyPrice, quote: Quote, draws: &[Draw], factors: PricingFactor, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Projection>, PricingError> {
+	return projection::get_all_projections(&ComponentOption { prices, factors }, quote, draws, coverage_levels, config);
+}
+
+pub fn get_convergence(prices: DailyPrice, quote: Quote, factors: PricingFactor, coverage_level: f64, config: &EngineConfig, settings: &ConvergenceSettings) -> Result<Vec<ConvergencePoint>, PricingError> {
+	return convergence::compare_convergence(&ComponentOption { prices, factors }, quote, coverage_level, config, settings);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_trace<W: Write + ?Sized>(prices: DailyPrice, quote: Quote, draws: &[Draw], factors: PricingFactor, coverage_level: f64, format: TraceFormat, config: &EngineConfig, writer: &mut W) -> Result<usize, PricingError> {
+	return trace::write_trace(&ComponentOption { prices, factors }, quote, draws, coverage_level, format, config, writer);
+}
+
+pub fn get_component_prices(products: &ProductPrice, factors: &PricingFactor, config: &EngineConfig) -> ComponentPrice {
+	match config.arithmetic {
+		Arithmetic::Float => calculate_component_prices::<f64>(products, factors),
+		Arithmetic::Decimal => calculate_component_prices::<DecimalNumber>(products, factors)
+	}
+}
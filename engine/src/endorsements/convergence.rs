@@ -0,0 +1,87 @@
+use ::common::helpers::{ensure_finite, validate_coverage_level};
+use ::common::random::{generate_draws, generate_sobol_draws, validate_draw_count};
+use ::endorsements::calculator::{calculate_endorsement, create_simulation_values};
+use ::endorsements::pricing_option::PricingOption;
+use ::errors::PricingError;
+use ::types::base_types::{ConvergencePoint, ConvergenceSettings, EngineConfig, Quote};
+
+// Most replications of each draw count
+pub const MAX_REPLICATIONS: usize = 100;
+
+// Most draws priced across every count and replication of both sequences,
+// the comparison runs on the calling thread
+pub const MAX_CONVERGENCE_DRAWS: usize = 10_000_000;
+
+// Prices the same quote from pseudo-random and scrambled Sobol draws, replication
+// `r` of each size using seed `seed + r` for both sequences
+pub fn compare_convergence<P: PricingOption>(option: &P, quote: Quote, coverage_level: f64, config: &EngineConfig, settings: &ConvergenceSettings) -> Result<Vec<ConvergencePoint>, PricingError> {
+	validate_coverage_level(coverage_level)?;
+
+	if settings.replications == 0 {
+		return Err(PricingError::InvalidConvergenceSettings("at least one replication is required".to_string()))
+	}
+
+	if settings.counts.is_empty() {
+		return Err(PricingError::InvalidConvergenceSettings("at least one draw count is required".to_string()))
+	}
+
+	if settings.replications > MAX_REPLICATIONS {
+		return Err(PricingError::InvalidConvergenceSettings(format!("at most {} replications can be run, got {}", MAX_REPLICATIONS, settings.replications)))
+	}
+
+	let mut total: usize = 0;
+
+	for count in settings.counts.iter() {
+		validate_draw_count(*count)?;
+		total = total.saturating_add(count.saturating_mul(settings.replications).saturating_mul(2));
+	}
+
+	if total > MAX_CONVERGENCE_DRAWS {
+		return Err(PricingError::InvalidConvergenceSettings(format!("at most {} draws can be priced in total, the counts and replications need {}", MAX_CONVERGENCE_DRAWS, total)))
+	}
+
+	let mut points = Vec::with_capacity(settings.counts.len());
+
+	for count in settings.counts.iter() {
+		let mut pseudo_random = Vec::with_capacity(settings.replications);
+		let mut sobol = Vec::with_capacity(settings.replications);
+
+		for replication in 0..settings.replications {
+			let seed = settings.seed.wrapping_add(replication as u64);
+			pseudo_random.push(gross_premium(option, quote, &generate_draws(seed, *count)?, coverage_level, config)?);
+			sobol.push(gross_premium(option, quote, &generate_sobol_draws(*count, Some(seed))?, coverage_level, config)?);
+		}
+
+		let (prm, prsd) = mean_and_standard_deviation(&pseudo_random);
+		let (sm, ssd) = mean_and_standard_deviation(&sobol);
+
+		points.push(ConvergencePoint {
+			draws: *count,
+			pseudoRandomMean: ensure_finite(prm, "pseudoRandomMean")?,
+			pseudoRandomStandardDeviation: ensure_finite(prsd, "pseudoRandomStandardDeviation")?,
+			sobolMean: ensure_finite(sm, "sobolMean")?,
+			sobolStandardDeviation: ensure_finite(ssd, "sobolStandardDeviation")?
+		});
+	}
+
+	return Ok(points)
+}
+
+fn gross_premium<P: PricingOption>(option: &P, quote: Quote, draws: &[P::Draw], coverage_level: f64, config: &EngineConfig) -> Result<f64, PricingError> {
+	let simulation_values = create_simulation_values(option, draws, config)?;
+	let endorsement = calculate_endorsement(option, quote, &simulation_values, coverage_level, config)?;
+	return Ok(endorsement.grossPremium)
+}
+
+// Sample standard deviation, zero for a single replication
+fn mean_and_standard_deviation(values: &[f64]) -> (f64, f64) {
+	let n = values.len() as f64;
+	let mean = values.iter().sum::<f64>() / n;
+
+	if values.len() < 2 {
+		return (mean, 0.0)
+	}
+
+	let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (n - 1.0);
+	return (mean, variance.sqrt())
+}
@@ -0,0 +1,97 @@
+use ::common::helpers::ensure_finite;
+use ::errors::PricingError;
+use ::types::base_types::{DistributionSettings, Histogram, LossDistribution, Percentiles};
+
+// Most bins a histogram can have, which keeps a bin count from JS from
+// exhausting memory when the counts are allocated
+pub const MAX_HISTOGRAM_BINS: usize = 10_000;
+
+// Statistics over every simulated revenue, mirrors included, for risk charts
+pub fn loss_distribution(revenues: &[f64], revenue_guarantee: f64, protection: f64, settings: &DistributionSettings) -> Result<LossDistribution, PricingError> {
+	if revenues.is_empty() {
+		return Err(PricingError::EmptyDraws)
+	}
+
+	if let Some(bins) = settings.histogram_bins {
+		if bins > MAX_HISTOGRAM_BINS {
+			return Err(PricingError::InvalidHistogramBins(bins))
+		}
+	}
+
+	let n = revenues.len() as f64;
+
+	let mut indemnities: Vec<f64> = revenues.iter().map(|revenue| {
+		let loss = revenue_guarantee - revenue;
+
+		if loss > 0.0 {
+			loss * protection
+		} else { 0.0 }
+	}).collect();
+
+	let paid: Vec<f64> = indemnities.iter().cloned().filter(|indemnity| *indemnity > 0.0).collect();
+
+	// Payout Probability
+	let pp: f64 = paid.len() as f64 / n;
+
+	// Mean Indemnity Given Loss
+	let migl: f64 = if paid.is_empty() {
+		0.0
+	} else {
+		paid.iter().sum::<f64>() / paid.len() as f64
+	};
+
+	let (revenue_histogram, indemnity_histogram) = match settings.histogram_bins {
+		Some(bins) if bins > 0 => (Some(histogram(revenues, bins)), Some(histogram(&indemnities, bins))),
+		_ => (None, None)
+	};
+
+	indemnities.sort_by(|a, b| a.partial_cmp(b).unwrap_or(::std::cmp::Ordering::Equal));
+
+	return Ok(LossDistribution {
+		payoutProbability: ensure_finite(pp, "payoutProbability")?,
+		meanIndemnityGivenLoss: ensure_finite(migl, "meanIndemnityGivenLoss")?,
+		indemnityPercentiles: Percentiles {
+			p5: ensure_finite(percentile(&indemnities, 0.05), "indemnityPercentiles")?,
+			p25: ensure_finite(percentile(&indemnities, 0.25), "indemnityPercentiles")?,
+			p50: ensure_finite(percentile(&indemnities, 0.50), "indemnityPercentiles")?,
+			p75: ensure_finite(percentile(&indemnities, 0.75), "indemnityPercentiles")?,
+			p95: ensure_finite(percentile(&indemnities, 0.95), "indemnityPercentiles")?,
+			p99: ensure_finite(percentile(&indemnities, 0.99), "indemnityPercentiles")?
+		},
+		revenueHistogram: revenue_histogram,
+		indemnityHistogram: indemnity_histogram
+	})
+}
+
+// Linear interpolation between the closest ranks of sorted values
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+	let rank = p * (sorted.len() - 1) as f64;
+	let lower = rank.floor() as usize;
+	let upper = rank.ceil() as usize;
+	let weight = rank - lower as f64;
+
+	sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+}
+
+// Equal width bins from the smallest to the largest value, the last bin closed
+fn histogram(values: &[f64], bins: usize) -> Histogram {
+	let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+	let max = values.iter().cloned().fold(-f64::INFINITY, f64::max);
+	let width = (max - min) / bins as f64;
+	let mut counts = vec![0; bins];
+
+	for value in values.iter() {
+		let bin = if width > 0.0 {
+			(((value - min) / width) as usize).min(bins - 1)
+		} else { 0 };
+
+		counts[bin] += 1;
+	}
+
+	return Histogram {
+		min,
+		max,
+		binWidth: width,
+		counts
+	}
+}
@@ -0,0 +1,42 @@
+use ::common::numeric::Numeric;
+use ::common::random::UniformDraw;
+use ::errors::PricingError;
+use ::types::base_types::Quote;
+
+// Named values for one draw, in column order
+pub type TraceFields = Vec<(&'static str, f64)>;
+
+// A pricing option supplies the option-specific steps of the endorsement
+// pipeline; everything else is shared by `calculator`. Steps that round are
+// generic over the `Numeric` type the call's config selects
+pub trait PricingOption: Sync {
+	type Draw: Sync + UniformDraw;
+	type SimulationValue: Send + Sync;
+	type ActualPrice;
+
+	// Loading factor applied to the preliminary total premium
+	fn loading_factor(&self) -> f64;
+
+	// Rejects prices that would make the simulation meaningless
+	fn validate(&self) -> Result<(), PricingError>;
+
+	// Leading months of the quarter whose prices are announced rather than simulated
+	fn known_months(&self) -> Result<usize, PricingError>;
+
+	// Expected price per cwt, weighted by the quote's class or component mix
+	fn expected_weighted_price<N: Numeric>(&self, quote: &Quote) -> f64;
+
+	// Simulated prices and yield adjustment factor for a single draw
+	fn simulate<N: Numeric>(&self, draw: &Self::Draw) -> Result<Self::SimulationValue, PricingError>;
+
+	// `simulate` plus the intermediate prices behind it, named for trace output
+	fn trace<N: Numeric>(&self, draw: &Self::Draw) -> Result<(Self::SimulationValue, TraceFields), PricingError>;
+
+	// Simulated price per cwt for a single draw, weighted like `expected_weighted_price`
+	fn simulated_weighted_price<N: Numeric>(&self, value: &Self::SimulationValue, quote: &Quote) -> f64;
+
+	fn simulated_yield_factor(&self, value: &Self::SimulationValue) -> f64;
+
+	// Announced prices and yield for the quarter, in the shape of a simulation value
+	fn settlement_value<N: Numeric>(&self, actual: &Self::ActualPrice) -> Result<Self::SimulationValue, PricingError>;
+}
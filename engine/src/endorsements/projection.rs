@@ -0,0 +1,77 @@
+use ::common::helpers::{ensure_finite, validate_coverage_level};
+use ::common::numeric::{DecimalNumber, Numeric};
+use ::endorsements::calculator::{create_simulation_values, expected_revenue_amount, simulated_loss, whole_dollars};
+use ::endorsements::pricing_option::PricingOption;
+use ::errors::PricingError;
+use ::types::base_types::{Arithmetic, EngineConfig, Projection, Quote};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub fn get_all_projections<P: PricingOption>(option: &P, quote: Quote, draws: &[P::Draw], coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Projection>, PricingError> {
+	let simulation_values = create_simulation_values(option, draws, config)?;
+	return calculate_all_projections(option, quote, &simulation_values, coverage_levels, config);
+}
+
+pub fn calculate_all_projections<P: PricingOption>(option: &P, quote: Quote, simulation_values: &[P::SimulationValue], coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Projection>, PricingError> {
+	for coverage_level in coverage_levels.iter() {
+		validate_coverage_level(*coverage_level)?;
+	}
+
+	let mut projections = Vec::with_capacity(coverage_levels.len());
+
+	for coverage_level in coverage_levels.iter() {
+		projections.push(calculate_projection(option, quote, simulation_values, *coverage_level, config)?);
+	}
+
+	return Ok(projections);
+}
+
+// Only the months that are still open vary between draws, so the losses
+// describe what the endorsement is likely to pay from here
+pub fn calculate_projection<P: PricingOption>(option: &P, quote: Quote, simulation_values: &[P::SimulationValue], coverage_level: f64, config: &EngineConfig) -> Result<Projection, PricingError> {
+	match config.arithmetic {
+		Arithmetic::Float => project::<f64, P>(option, quote, simulation_values, coverage_level),
+		Arithmetic::Decimal => project::<DecimalNumber, P>(option, quote, simulation_values, coverage_level)
+	}
+}
+
+fn project<N: Numeric, P: PricingOption>(option: &P, quote: Quote, simulation_values: &[P::SimulationValue], coverage_level: f64) -> Result<Projection, PricingError> {
+	validate_coverage_level(coverage_level)?;
+
+	if simulation_values.is_empty() {
+		return Err(PricingError::EmptyDraws)
+	}
+
+	// Known Months
+	let km: usize = option.known_months()?;
+
+	// Revenue Guarantee
+	let rg: f64 = whole_dollars::<N>(expected_revenue_amount::<N, P>(option, &quote), coverage_level);
+
+	#[cfg(not(feature = "parallel"))]
+	let simulation_values = simulation_values.iter();
+
+	#[cfg(feature = "parallel")]
+	let simulation_values = simulation_values.par_iter();
+
+	// Simulated Losses
+	let _sl: Vec<f64> = simulation_values.map(|element| simulated_loss::<N, P>(option, element, &quote, rg)).collect::<Result<_, PricingError>>()?;
+
+	// Average Simulated Loss
+	let asl: f64 = _sl.iter().fold(0.0, |acc, x| acc + x) / _sl.len() as f64;
+
+	// Expected Indemnity
+	let ei: f64 = whole_dollars::<N>(asl, quote.protection);
+
+	// Payout Probability
+	let pp: f64 = _sl.iter().filter(|loss| **loss > 0.0).count() as f64 / _sl.len() as f64;
+
+	return Ok(Projection {
+		level: coverage_level,
+		revenueGuarantee: ensure_finite(rg, "revenueGuarantee")?,
+		expectedIndemnity: ensure_finite(ei, "expectedIndemnity")?,
+		payoutProbability: ensure_finite(pp, "payoutProbability")?,
+		knownMonths: km
+	})
+}
@@ -0,0 +1,55 @@
+use ::endorsements::calculator;
+use ::endorsements::class_endorsement::ClassOption;
+use ::endorsements::component_endorsement::ComponentOption;
+use ::endorsements::pricing_option::PricingOption;
+use ::endorsements::projection;
+use ::errors::PricingError;
+use ::types::base_types::{Endorsement, EngineConfig, Projection, Quote};
+
+pub type ClassSession = PricingSession<ClassOption>;
+pub type ComponentSession = PricingSession<ComponentOption>;
+
+// Holds the simulation values for one day's prices and draws so that
+// every quote priced against them only runs the loss loop
+pub struct PricingSession<P: PricingOption> {
+	option: P,
+	config: EngineConfig,
+	simulation_values: Vec<P::SimulationValue>
+}
+
+impl<P: PricingOption> PricingSession<P> {
+	pub fn new(option: P, draws: &[P::Draw], config: EngineConfig) -> Result<PricingSession<P>, PricingError> {
+		let simulation_values = calculator::create_simulation_values(&option, draws, &config)?;
+
+		return Ok(PricingSession {
+			option,
+			config,
+			simulation_values
+		})
+	}
+
+	// Draws supplied, not counting the antithetic mirrors
+	pub fn draw_count(&self) -> usize {
+		if self.config.antithetic {
+			self.simulation_values.len() / 2
+		} else {
+			self.simulation_values.len()
+		}
+	}
+
+	pub fn config(&self) -> &EngineConfig {
+		&self.config
+	}
+
+	pub fn price(&self, quote: Quote, coverage_level: f64) -> Result<Endorsement, PricingError> {
+		return calculator::calculate_endorsement(&self.option, quote, &self.simulation_values, coverage_level, &self.config);
+	}
+
+	pub fn price_all(&self, quote: Quote, coverage_levels: &[f64]) -> Result<Vec<Endorsement>, PricingError> {
+		return calculator::calculate_all_endorsements(&self.option, quote, &self.simulation_values, coverage_levels, &self.config);
+	}
+
+	pub fn project_all(&self, quote: Quote, coverage_levels: &[f64]) -> Result<Vec<Projection>, PricingError> {
+		return projection::calculate_all_projections(&self.option, quote, &self.simulation_values, coverage_levels, &self.config);
+	}
+}
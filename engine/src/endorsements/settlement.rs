@@ -0,0 +1,144 @@
+use ::common::helpers::{ensure_finite, validate_coverage_level};
+use ::common::numeric::{DecimalNumber, Numeric};
+use ::endorsements::calculator::{expected_revenue_amount, per_cwt, simulated_revenue_amount, whole_dollars};
+use ::endorsements::pricing_option::PricingOption;
+use ::errors::PricingError;
+use ::types::base_types::{Arithmetic, EngineConfig, Quote, Settlement};
+
+pub fn get_all_settlements<P: PricingOption>(option: &P, quote: Quote, actual: &P::ActualPrice, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Settlement>, PricingError> {
+	match config.arithmetic {
+		Arithmetic::Float => settle_all::<f64, P>(option, quote, actual, coverage_levels),
+		Arithmetic::Decimal => settle_all::<DecimalNumber, P>(option, quote, actual, coverage_levels)
+	}
+}
+
+fn settle_all<N: Numeric, P: PricingOption>(option: &P, quote: Quote, actual: &P::ActualPrice, coverage_levels: &[f64]) -> Result<Vec<Settlement>, PricingError> {
+	for coverage_level in coverage_levels.iter() {
+		validate_coverage_level(*coverage_level)?;
+	}
+
+	let value = option.settlement_value::<N>(actual)?;
+	let mut settlements = Vec::with_capacity(coverage_levels.len());
+
+	for coverage_level in coverage_levels.iter() {
+		settlements.push(calculate_settlement::<N, P>(option, quote, &value, *coverage_level)?);
+	}
+
+	return Ok(settlements);
+}
+
+// Runs the announced prices through the same revenue formula as a single simulated draw
+pub fn calculate_settlement<N: Numeric, P: PricingOption>(option: &P, quote: Quote, value: &P::SimulationValue, coverage_level: f64) -> Result<Settlement, PricingError> {
+	validate_coverage_level(coverage_level)?;
+
+	// Inputs
+    let _dp: f64 = quote.dp;
+    let _protection: f64 = quote.protection;
+
+    // Expected Revenue Amount
+    let _era: f64 = expected_revenue_amount::<N, P>(option, &quote);
+
+    // Revenue Guarantee
+    let rg: f64 = whole_dollars::<N>(_era, coverage_level);
+
+    // Coverage Price
+    let cp: f64 = (N::from_f64(option.expected_weighted_price::<N>(&quote)) * N::from_f64(coverage_level)).to_f64();
+
+    // Actual Weighted Price
+    let awp: f64 = option.simulated_weighted_price::<N>(value, &quote);
+
+    // Actual Yield Adjustment Factor
+    let yaf: f64 = option.simulated_yield_factor(value);
+
+    // Actual Revenue Amount
+    let ara: f64 = simulated_revenue_amount::<N, P>(option, value, &quote)?;
+
+    // Gross Indemnity
+    let gi: f64 = if rg > ara {
+        rg - ara
+    } else { 0.0 };
+
+    // Indemnity
+    let ind: f64 = whole_dollars::<N>(gi, _protection);
+
+    // Covered Milk, cwt
+    let cm: f64 = (_dp * _protection) / 100.0;
+
+    return Ok(Settlement {
+        level: coverage_level,
+        revenueGuarantee: ensure_finite(rg, "revenueGuarantee")?,
+        actualRevenue: ensure_finite(ara, "actualRevenue")?,
+        indemnity: ensure_finite(ind, "indemnity")?,
+        coveragePrice: ensure_finite(cp, "coveragePrice")?,
+        actualPrice: ensure_finite(awp, "actualPrice")?,
+        indemnityPerCwt: ensure_finite(per_cwt(ind, cm), "indemnityPerCwt")?,
+        yieldAdjustmentFactor: ensure_finite(yaf, "yieldAdjustmentFactor")?
+    })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ::endorsements::class_endorsement::ClassOption;
+	use ::types::class_types::{ActualPrice, DailyPrice};
+
+	fn option() -> ClassOption {
+		let prices: DailyPrice = serde_json::from_str(r#"{
+			"expectedClassThreePrice": 17.25, "expectedClassFourPrice": 16.10, "loadingFactor": 1.0,
+			"monthOneClassThreeSigma": 0.312, "monthOneExpectedClassThreePrice": 17.0,
+			"monthTwoClassThreeSigma": 0.315, "monthTwoExpectedClassThreePrice": 17.3,
+			"monthThreeClassThreeSigma": 0.318, "monthThreeExpectedClassThreePrice": 17.45,
+			"monthOneClassFourSigma": 0.311, "monthOneExpectedClassFourPrice": 16.0,
+			"monthTwoClassFourSigma": 0.314, "monthTwoExpectedClassFourPrice": 16.1,
+			"monthThreeClassFourSigma": 0.317, "monthThreeExpectedClassFourPrice": 16.2,
+			"expectedYield": 2000.0, "expectedYieldStandardDeviation": 40.0
+		}"#).unwrap();
+
+		return ClassOption { prices }
+	}
+
+	fn quote(protection: f64) -> Quote {
+		let mut quote: Quote = serde_json::from_str(r#"{"classWeight": 0.5, "butterfat": 3.9, "protein": 3.1, "production": 1000000.0, "protection": 1.0}"#).unwrap();
+		quote.protection = protection;
+		return quote
+	}
+
+	// Class III averages 15.00 and Class IV 14.00 over the quarter
+	fn actual() -> ActualPrice {
+		serde_json::from_str(r#"{
+			"monthOneClassThreePrice": 14.9, "monthTwoClassThreePrice": 15.0, "monthThreeClassThreePrice": 15.1,
+			"monthOneClassFourPrice": 14.2, "monthTwoClassFourPrice": 14.0, "monthThreeClassFourPrice": 13.8,
+			"yieldAdjustmentFactor": 0.98
+		}"#).unwrap()
+	}
+
+	#[test]
+	fn settles_from_the_announced_prices() {
+		for config in [EngineConfig::default(), EngineConfig { arithmetic: Arithmetic::Decimal, ..EngineConfig::default() }].iter() {
+			let settlements = get_all_settlements(&option(), quote(0.8), &actual(), &[0.80, 0.90], config).unwrap();
+
+			// Expected revenue is 16.675 $/cwt on 10000 cwt, 133400 and 150075 guaranteed
+			assert_eq!(settlements[0].revenueGuarantee, 133400.0);
+			assert_eq!(settlements[0].indemnity, 0.0);
+			assert_eq!(settlements[0].indemnityPerCwt, 0.0);
+
+			// 14.50 $/cwt on 9800 cwt after the yield adjustment
+			let settlement = &settlements[1];
+			assert_eq!(settlement.revenueGuarantee, 150075.0);
+			assert_eq!(settlement.actualPrice, 14.5);
+			assert_eq!(settlement.actualRevenue, 142100.0);
+			assert!((settlement.coveragePrice - 15.0075).abs() < 1e-9);
+
+			// (150075 - 142100) * 0.8 over 8000 cwt of covered milk
+			assert_eq!(settlement.indemnity, 6380.0);
+			assert_eq!(settlement.indemnityPerCwt, 0.7975);
+		}
+	}
+
+	#[test]
+	fn settles_no_covered_milk_to_zero() {
+		let settlements = get_all_settlements(&option(), quote(0.0), &actual(), &[0.90], &EngineConfig::default()).unwrap();
+		assert_eq!(settlements[0].indemnity, 0.0);
+		assert_eq!(settlements[0].indemnityPerCwt, 0.0);
+	}
+}
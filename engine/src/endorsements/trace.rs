@@ -0,0 +1,70 @@
+use std::io::Write;
+
+use serde_json;
+
+use ::common::helpers::validate_coverage_level;
+use ::common::numeric::{DecimalNumber, Numeric};
+use ::endorsements::calculator::{expected_revenue_amount, simulated_loss, simulated_revenue_amount, whole_dollars};
+use ::endorsements::pricing_option::PricingOption;
+use ::errors::PricingError;
+use ::types::base_types::{Arithmetic, EngineConfig, Quote, TraceFormat};
+
+// Writes one row per supplied draw: its index, the option's intermediate prices and
+// simulation value, then the simulated revenue and loss at `coverage_level`.
+// Only the config's arithmetic applies, draws are traced as supplied
+pub fn write_trace<P: PricingOption, W: Write + ?Sized>(option: &P, quote: Quote, draws: &[P::Draw], coverage_level: f64, format: TraceFormat, config: &EngineConfig, writer: &mut W) -> Result<usize, PricingError> {
+	match config.arithmetic {
+		Arithmetic::Float => write_rows::<f64, P, W>(option, quote, draws, coverage_level, format, writer),
+		Arithmetic::Decimal => write_rows::<DecimalNumber, P, W>(option, quote, draws, coverage_level, format, writer)
+	}
+}
+
+fn write_rows<N: Numeric, P: PricingOption, W: Write + ?Sized>(option: &P, quote: Quote, draws: &[P::Draw], coverage_level: f64, format: TraceFormat, writer: &mut W) -> Result<usize, PricingError> {
+	validate_coverage_level(coverage_level)?;
+
+	if draws.is_empty() {
+		return Err(PricingError::EmptyDraws)
+	}
+
+	option.validate()?;
+
+	// Revenue Guarantee
+	let rg: f64 = whole_dollars::<N>(expected_revenue_amount::<N, P>(option, &quote), coverage_level);
+
+	for (index, draw) in draws.iter().enumerate() {
+		let (value, mut fields) = option.trace::<N>(draw)?;
+		fields.push(("simulatedRevenue", simulated_revenue_amount::<N, P>(option, &value, &quote)?));
+		fields.push(("simulatedLoss", simulated_loss::<N, P>(option, &value, &quote, rg)?));
+
+		match format {
+			TraceFormat::Csv => {
+				if index == 0 {
+					let header: Vec<&str> = fields.iter().map(|field| field.0).collect();
+					writeln!(writer, "draw,{}", header.join(",")).map_err(write_failed)?;
+				}
+
+				let row: Vec<String> = fields.iter().map(|field| field.1.to_string()).collect();
+				writeln!(writer, "{},{}", index, row.join(",")).map_err(write_failed)?;
+			},
+			TraceFormat::JsonLines => {
+				let mut line = format!("{{\"draw\":{}", index);
+
+				for field in fields.iter() {
+					// Non-finite values become null, as serde_json writes them
+					let number = serde_json::to_string(&field.1).map_err(|err| PricingError::TraceWriteFailed(err.to_string()))?;
+					line.push_str(&format!(",\"{}\":{}", field.0, number));
+				}
+
+				writeln!(writer, "{}}}", line).map_err(write_failed)?;
+			}
+		}
+	}
+
+	writer.flush().map_err(write_failed)?;
+
+	return Ok(draws.len())
+}
+
+fn write_failed(err: ::std::io::Error) -> PricingError {
+	PricingError::TraceWriteFailed(err.to_string())
+}
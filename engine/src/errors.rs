@@ -0,0 +1,75 @@
+use std::error::Error;
+use std::fmt;
+
+use ::common::random::MAX_DRAW_COUNT;
+use ::common::sobol::SOBOL_MAX_DIMENSION;
+use ::endorsements::distribution::MAX_HISTOGRAM_BINS;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PricingError {
+	InvalidProbability(f64),
+	InvalidSigma(f64),
+	InvalidExpectedYield(f64),
+	InvalidExpectedPrice(f64),
+	InvalidCoverageLevel(f64),
+	InvalidSubsidySchedule(String),
+	UnknownCropYear(u16),
+	InvalidKnownPrices(String),
+	InvalidCorrelationMatrix(String),
+	TraceWriteFailed(String),
+	InvalidDrawCount(usize),
+	InvalidHistogramBins(usize),
+	InvalidSobolDimension(usize),
+	InvalidConvergenceSettings(String),
+	EmptyDraws,
+	NonFiniteResult(&'static str)
+}
+
+impl PricingError {
+	// Stable identifier surfaced as the `code` property of the thrown JS error
+	pub fn code(&self) -> &'static str {
+		match *self {
+			PricingError::InvalidProbability(_) => "ERR_INVALID_PROBABILITY",
+			PricingError::InvalidSigma(_) => "ERR_INVALID_SIGMA",
+			PricingError::InvalidExpectedYield(_) => "ERR_INVALID_EXPECTED_YIELD",
+			PricingError::InvalidExpectedPrice(_) => "ERR_INVALID_EXPECTED_PRICE",
+			PricingError::InvalidCoverageLevel(_) => "ERR_INVALID_COVERAGE_LEVEL",
+			PricingError::InvalidSubsidySchedule(_) => "ERR_INVALID_SUBSIDY_SCHEDULE",
+			PricingError::UnknownCropYear(_) => "ERR_UNKNOWN_CROP_YEAR",
+			PricingError::InvalidKnownPrices(_) => "ERR_INVALID_KNOWN_PRICES",
+			PricingError::InvalidCorrelationMatrix(_) => "ERR_INVALID_CORRELATION_MATRIX",
+			PricingError::TraceWriteFailed(_) => "ERR_TRACE_WRITE_FAILED",
+			PricingError::InvalidDrawCount(_) => "ERR_INVALID_DRAW_COUNT",
+			PricingError::InvalidHistogramBins(_) => "ERR_INVALID_HISTOGRAM_BINS",
+			PricingError::InvalidSobolDimension(_) => "ERR_INVALID_SOBOL_DIMENSION",
+			PricingError::InvalidConvergenceSettings(_) => "ERR_INVALID_CONVERGENCE_SETTINGS",
+			PricingError::EmptyDraws => "ERR_EMPTY_DRAWS",
+			PricingError::NonFiniteResult(_) => "ERR_NON_FINITE_RESULT"
+		}
+	}
+}
+
+impl fmt::Display for PricingError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			PricingError::InvalidProbability(p) => write!(f, "The probability p must satisfy 0 < p < 1, got {}", p),
+			PricingError::InvalidSigma(sigma) => write!(f, "The value of sigma must be positive, got {}", sigma),
+			PricingError::InvalidExpectedYield(ey) => write!(f, "The expected yield must be greater than 0, got {}", ey),
+			PricingError::InvalidExpectedPrice(price) => write!(f, "The expected price must be a finite number greater than 0, got {}", price),
+			PricingError::InvalidCoverageLevel(level) => write!(f, "The coverage level must be one of 0.70, 0.75, 0.80, 0.85, 0.90 or 0.95, got {}", level),
+			PricingError::InvalidSubsidySchedule(ref reason) => write!(f, "Invalid subsidy schedule: {}", reason),
+			PricingError::UnknownCropYear(year) => write!(f, "No subsidy schedule covers crop year {}", year),
+			PricingError::InvalidKnownPrices(ref reason) => write!(f, "Invalid known prices: {}", reason),
+			PricingError::InvalidCorrelationMatrix(ref reason) => write!(f, "Invalid correlation matrix: {}", reason),
+			PricingError::TraceWriteFailed(ref reason) => write!(f, "Could not write the simulation trace: {}", reason),
+			PricingError::InvalidDrawCount(count) => write!(f, "At most {} draws can be generated, got {}", MAX_DRAW_COUNT, count),
+			PricingError::InvalidHistogramBins(bins) => write!(f, "At most {} histogram bins can be built, got {}", MAX_HISTOGRAM_BINS, bins),
+			PricingError::InvalidSobolDimension(dimension) => write!(f, "Sobol directions are only defined for {} dimensions, got {}", SOBOL_MAX_DIMENSION, dimension),
+			PricingError::InvalidConvergenceSettings(ref reason) => write!(f, "Invalid convergence settings: {}", reason),
+			PricingError::EmptyDraws => write!(f, "At least one draw is required to run the simulation"),
+			PricingError::NonFiniteResult(field) => write!(f, "The calculated {} is not a finite number", field)
+		}
+	}
+}
+
+impl Error for PricingError {}
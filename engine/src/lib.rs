@@ -0,0 +1,98 @@
+#![allow(clippy::needless_return)]
+
+#[macro_use]
+extern crate serde_derive;
+
+extern crate serde;
+extern crate serde_json;
+extern crate rust_decimal;
+
+#[cfg(feature = "parallel")]
+extern crate rayon;
+
+pub mod common;
+pub mod errors;
+pub mod types;
+pub mod endorsements;
+
+pub use endorsements::class_endorsement::ClassOption;
+pub use endorsements::component_endorsement::ComponentOption;
+pub use endorsements::pricing_option::PricingOption;
+pub use endorsements::session::{ClassSession, ComponentSession, PricingSession};
+pub use common::helpers::{COVERAGE_LEVELS, DEFAULT_COVERAGE_LEVELS};
+pub use errors::PricingError;
+pub use common::random::{generate_correlated_draws, generate_draws, generate_sobol_draws, validate_draw_count, CorrelationMatrix, DrawGenerator, UniformDraw, UniformSource, MAX_DRAW_COUNT};
+pub use common::sobol::SobolSequence;
+pub use endorsements::convergence::{MAX_CONVERGENCE_DRAWS, MAX_REPLICATIONS};
+pub use endorsements::distribution::MAX_HISTOGRAM_BINS;
+pub use common::numeric::{DecimalNumber, Numeric};
+pub use types::base_types::{Arithmetic, ConvergencePoint, ConvergenceSettings, DistributionSettings, DrawSequence, DrawSet, Endorsement, EngineConfig, Histogram, LossDistribution, Output, Percentiles, Projection, Quote, SeededDraws, Settlement, TraceFormat, WorksheetStep};
+pub use types::subsidy_types::{SubsidyRate, SubsidySchedule, SubsidyTable};
+pub use types::class_types;
+pub use types::component_types;
+
+use std::io::Write;
+
+use endorsements::class_endorsement;
+use endorsements::component_endorsement;
+
+// Prices the class option at each level of the coverage ladder
+pub fn price_class(prices: class_types::DailyPrice, quote: Quote, draws: &[class_types::Draw], coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Endorsement>, PricingError> {
+	class_endorsement::get_all_endorsements(prices, quote, draws, coverage_levels, config)
+}
+
+pub fn price_class_endorsement(prices: class_types::DailyPrice, quote: Quote, draws: &[class_types::Draw], coverage_level: f64, config: &EngineConfig) -> Result<Endorsement, PricingError> {
+	class_endorsement::get_endorsement(prices, quote, draws, coverage_level, config)
+}
+
+// Prices the component option at each level of the coverage ladder
+pub fn price_component(prices: component_types::DailyPrice, quote: Quote, draws: &[component_types::Draw], factors: component_types::PricingFactor, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Endorsement>, PricingError> {
+	component_endorsement::get_all_endorsements(prices, quote, draws, factors, coverage_levels, config)
+}
+
+pub fn price_component_endorsement(prices: component_types::DailyPrice, quote: Quote, draws: &[component_types::Draw], factors: component_types::PricingFactor, coverage_level: f64, config: &EngineConfig) -> Result<Endorsement, PricingError> {
+	component_endorsement::get_endorsement(prices, quote, draws, factors, coverage_level, config)
+}
+
+// Indemnity for each coverage level from the announced class prices
+pub fn settle_class(prices: class_types::DailyPrice, quote: Quote, actual: &class_types::ActualPrice, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Settlement>, PricingError> {
+	class_endorsement::get_settlements(prices, quote, actual, coverage_levels, config)
+}
+
+// Indemnity for each coverage level from the announced component prices
+pub fn settle_component(prices: component_types::DailyPrice, quote: Quote, actual: &component_types::ActualPrice, factors: component_types::PricingFactor, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Settlement>, PricingError> {
+	component_endorsement::get_settlements(prices, quote, actual, factors, coverage_levels, config)
+}
+
+// Expected indemnity mid-quarter, simulating only the months without announced prices
+pub fn project_class(prices: class_types::DailyPrice, quote: Quote, draws: &[class_types::Draw], coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Projection>, PricingError> {
+	class_endorsement::get_projections(prices, quote, draws, coverage_levels, config)
+}
+
+pub fn project_component(prices: component_types::DailyPrice, quote: Quote, draws: &[component_types::Draw], factors: component_types::PricingFactor, coverage_levels: &[f64], config: &EngineConfig) -> Result<Vec<Projection>, PricingError> {
+	component_endorsement::get_projections(prices, quote, draws, factors, coverage_levels, config)
+}
+
+// Premium wobble from pseudo-random and Sobol draws as the draw count grows
+pub fn compare_class_convergence(prices: class_types::DailyPrice, quote: Quote, coverage_level: f64, config: &EngineConfig, settings: &ConvergenceSettings) -> Result<Vec<ConvergencePoint>, PricingError> {
+	class_endorsement::get_convergence(prices, quote, coverage_level, config, settings)
+}
+
+pub fn compare_component_convergence(prices: component_types::DailyPrice, quote: Quote, factors: component_types::PricingFactor, coverage_level: f64, config: &EngineConfig, settings: &ConvergenceSettings) -> Result<Vec<ConvergencePoint>, PricingError> {
+	component_endorsement::get_convergence(prices, quote, factors, coverage_level, config, settings)
+}
+
+// One row per draw with its simulated prices, revenue and loss, returns the row count
+pub fn trace_class<W: Write + ?Sized>(prices: class_types::DailyPrice, quote: Quote, draws: &[class_types::Draw], coverage_level: f64, format: TraceFormat, config: &EngineConfig, writer: &mut W) -> Result<usize, PricingError> {
+	class_endorsement::write_trace(prices, quote, draws, coverage_level, format, config, writer)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn trace_component<W: Write + ?Sized>(prices: component_types::DailyPrice, quote: Quote, draws: &[component_types::Draw], factors: component_types::PricingFactor, coverage_level: f64, format: TraceFormat, config: &EngineConfig, writer: &mut W) -> Result<usize, PricingError> {
+	component_endorsement::write_trace(prices, quote, draws, factors, coverage_level, format, config, writer)
+}
+
+// Butterfat, protein and other solids prices from the announced product prices
+pub fn component_prices(products: &component_types::ProductPrice, factors: &component_types::PricingFactor, config: &EngineConfig) -> component_types::ComponentPrice {
+	component_endorsement::get_component_prices(products, factors, config)
+}
@@ -0,0 +1,4 @@
+pub mod base_types;
+pub mod class_types;
+pub mod component_types;
+pub mod subsidy_types;
\ No newline at end of file
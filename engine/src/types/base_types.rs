@@ -0,0 +1,366 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+use ::common::random::{draws_from_source, CorrelationMatrix, DrawGenerator, UniformDraw};
+use ::common::sobol::SobolSequence;
+use ::errors::PricingError;
+use ::types::subsidy_types::{SubsidySchedule, SubsidyTable};
+
+#[allow(non_snake_case)]
+#[derive(Serialize)]
+pub struct Endorsement {
+	// Premiums in dollars divided by 10000, kept for existing callers
+	pub subsidy: f64,
+	pub baseSubsidy: f64,
+	pub enhancedSubsidy: f64,
+	pub netPremium: f64,
+	pub grossPremium: f64,
+	pub protectedPrice: f64,
+	pub level: f64,
+	pub revenueGuarantee: f64,
+	pub liability: f64,
+
+	// Simulation noise: spread of the per-draw losses, the standard error of
+	// their mean, and 95% confidence intervals on the premiums. All are scaled
+	// like `grossPremium`, times protection and loading factor over 10000. The
+	// intervals close on the premiums when the premium floor sets them
+	pub lossStandardDeviation: f64,
+	pub lossStandardError: f64,
+	pub grossPremiumLower: f64,
+	pub grossPremiumUpper: f64,
+	pub netPremiumLower: f64,
+	pub netPremiumUpper: f64,
+
+	// Declared production times the protection factor, in cwt
+	pub coveredMilk: f64,
+
+	// Premiums in dollars for the whole endorsement
+	pub grossPremiumDollars: f64,
+	pub subsidyDollars: f64,
+	pub producerPremiumDollars: f64,
+
+	// The same premiums in dollars per cwt of covered milk
+	pub grossPremiumPerCwt: f64,
+	pub subsidyPerCwt: f64,
+	pub producerPremiumPerCwt: f64,
+
+	// What the producer can expect back, in dollars, and against the premium
+	pub expectedIndemnity: f64,
+	pub expectedLossRatio: f64,
+	pub expectedNetBenefit: f64,
+
+	// Only present when the config asks for it
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub lossDistribution: Option<LossDistribution>,
+
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub worksheet: Option<Vec<WorksheetStep>>
+}
+
+// One intermediate of the premium calculation, before premiums are scaled by 10000
+#[derive(Serialize)]
+pub struct WorksheetStep {
+	pub step: &'static str,
+	pub value: f64,
+	pub rounding: &'static str
+}
+
+impl WorksheetStep {
+	pub fn new(step: &'static str, value: f64, rounding: &'static str) -> WorksheetStep {
+		WorksheetStep { step, value, rounding }
+	}
+}
+
+// Per-draw outcomes of one endorsement, indemnities in dollars
+#[allow(non_snake_case)]
+#[derive(Serialize)]
+pub struct LossDistribution {
+	pub payoutProbability: f64,
+	pub meanIndemnityGivenLoss: f64,
+	pub indemnityPercentiles: Percentiles,
+
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub revenueHistogram: Option<Histogram>,
+
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub indemnityHistogram: Option<Histogram>
+}
+
+#[derive(Serialize)]
+pub struct Percentiles {
+	pub p5: f64,
+	pub p25: f64,
+	pub p50: f64,
+	pub p75: f64,
+	pub p95: f64,
+	pub p99: f64
+}
+
+#[allow(non_snake_case)]
+#[derive(Serialize)]
+pub struct Histogram {
+	pub min: f64,
+	pub max: f64,
+	pub binWidth: f64,
+	pub counts: Vec<usize>
+}
+
+// Indemnity owed on an endorsement once the quarter's prices are announced
+#[allow(non_snake_case)]
+#[derive(Serialize)]
+pub struct Settlement {
+	pub level: f64,
+	pub revenueGuarantee: f64,
+	pub actualRevenue: f64,
+	pub indemnity: f64,
+
+	// $/cwt, the actual price is before the yield adjustment
+	pub coveragePrice: f64,
+	pub actualPrice: f64,
+	pub indemnityPerCwt: f64,
+
+	pub yieldAdjustmentFactor: f64
+}
+
+// Expected indemnity part way through the quarter, with the known months fixed
+#[allow(non_snake_case)]
+#[derive(Serialize)]
+pub struct Projection {
+	pub level: f64,
+	pub revenueGuarantee: f64,
+	pub expectedIndemnity: f64,
+	pub payoutProbability: f64,
+	pub knownMonths: usize
+}
+
+// Spread of the gross premium across repeated draw sets of one size
+#[allow(non_snake_case)]
+#[derive(Serialize)]
+pub struct ConvergencePoint {
+	pub draws: usize,
+	pub pseudoRandomMean: f64,
+	pub pseudoRandomStandardDeviation: f64,
+	pub sobolMean: f64,
+	pub sobolStandardDeviation: f64
+}
+
+#[derive(Deserialize, Copy, Clone)]
+pub struct Quote {
+    #[serde(rename = "classWeight")]
+    pub cw: f64,
+
+    #[serde(rename = "butterfat")]
+    pub dbt: f64,
+
+    #[serde(rename = "protein")]
+    pub dpt: f64,
+
+    #[serde(rename = "production")]
+    pub dp: f64,
+
+    #[serde(rename = "protection")]
+    pub protection: f64,
+
+    #[serde(rename = "beginningFarmer", default)]
+    pub bfr: bool,
+
+    #[serde(rename = "veteranFarmer", default)]
+    pub vfr: bool
+}
+
+#[derive(Serialize)]
+pub struct Output {
+	pub performance: String,
+	pub endorsements: Vec<Endorsement>,
+	pub antithetic: bool
+}
+
+// Settings for a pricing call that don't belong to the quote
+#[derive(Deserialize, Clone, Default)]
+pub struct EngineConfig {
+	// Selects the subsidy schedule, the latest one is used when omitted
+	#[serde(rename = "cropYear", default)]
+	pub crop_year: Option<u16>,
+
+	#[serde(rename = "subsidySchedules", default)]
+	pub subsidy_schedules: SubsidyTable,
+
+	// Pairs every draw `u` with `1 - u` and averages each pair's loss
+	#[serde(default)]
+	pub antithetic: bool,
+
+	// Adds the step by step worksheet to every endorsement
+	#[serde(default)]
+	pub explain: bool,
+
+	// Adds loss distribution statistics to every endorsement when set
+	#[serde(rename = "lossDistribution", default)]
+	pub loss_distribution: Option<DistributionSettings>,
+
+	// Number type for the steps that round, decimal is slower but rounds like the official calculator
+	#[serde(default)]
+	pub arithmetic: Arithmetic
+}
+
+#[derive(Deserialize, Copy, Clone, Debug, Default, PartialEq)]
+pub enum Arithmetic {
+	#[default]
+	#[serde(rename = "float")]
+	Float,
+
+	#[serde(rename = "decimal")]
+	Decimal
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct DistributionSettings {
+	// Bins for the revenue and indemnity histograms, none are built when omitted
+	#[serde(rename = "histogramBins", default)]
+	pub histogram_bins: Option<usize>
+}
+
+impl EngineConfig {
+	pub fn subsidy_schedule(&self) -> Result<&SubsidySchedule, PricingError> {
+		self.subsidy_schedules.schedule_for(self.crop_year)
+	}
+}
+
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq)]
+pub enum TraceFormat {
+	#[serde(rename = "csv")]
+	Csv,
+
+	#[serde(rename = "jsonLines")]
+	JsonLines
+}
+
+// Draw set sizes to compare, each priced `replications` times from consecutive seeds
+#[derive(Deserialize, Clone, Debug)]
+pub struct ConvergenceSettings {
+	pub seed: u64,
+	pub counts: Vec<usize>,
+
+	#[serde(default = "default_replications")]
+	pub replications: usize
+}
+
+fn default_replications() -> usize {
+	10
+}
+
+// Draws to generate in the engine instead of shipping them with the call
+#[derive(Deserialize, Clone, Debug)]
+pub struct SeededDraws {
+	// Ignored by the unscrambled Sobol sequence
+	pub seed: u64,
+	pub count: usize,
+
+	#[serde(default)]
+	pub sequence: DrawSequence,
+
+	// Rows in draw field order: three months per commodity, then yield
+	#[serde(default)]
+	pub correlation: Option<Vec<Vec<f64>>>
+}
+
+#[derive(Deserialize, Copy, Clone, Debug, Default, PartialEq)]
+pub enum DrawSequence {
+	#[default]
+	#[serde(rename = "pseudoRandom")]
+	PseudoRandom,
+
+	#[serde(rename = "sobol")]
+	Sobol,
+
+	#[serde(rename = "scrambledSobol")]
+	ScrambledSobol
+}
+
+impl SeededDraws {
+	pub fn generate<D: UniformDraw>(&self) -> Result<Vec<D>, PricingError> {
+		let matrix = match self.correlation {
+			Some(ref rows) => Some(CorrelationMatrix::new(rows, D::DIMENSION)?),
+			None => None
+		};
+
+		return match self.sequence {
+			DrawSequence::PseudoRandom => draws_from_source(&mut DrawGenerator::new(self.seed), self.count, matrix.as_ref()),
+			DrawSequence::Sobol => draws_from_source(&mut SobolSequence::new(D::DIMENSION, None)?, self.count, matrix.as_ref()),
+			DrawSequence::ScrambledSobol => draws_from_source(&mut SobolSequence::new(D::DIMENSION, Some(self.seed))?, self.count, matrix.as_ref())
+		}
+	}
+}
+
+// Either an explicit array of draws or a `{ seed, count }` to generate them from
+pub enum DrawSet<D> {
+	Explicit(Vec<D>),
+	Seeded(SeededDraws)
+}
+
+// Picks the variant from the JSON shape rather than trying each in turn, so a
+// malformed draw still reports the field that is wrong
+impl<'de, D: Deserialize<'de>> Deserialize<'de> for DrawSet<D> {
+	fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<DrawSet<D>, De::Error> {
+		deserializer.deserialize_any(DrawSetVisitor(PhantomData))
+	}
+}
+
+struct DrawSetVisitor<D>(PhantomData<D>);
+
+impl<'de, D: Deserialize<'de>> Visitor<'de> for DrawSetVisitor<D> {
+	type Value = DrawSet<D>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "an array of draws or an object with a seed and count")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<DrawSet<D>, A::Error> {
+		Vec::deserialize(SeqAccessDeserializer::new(seq)).map(DrawSet::Explicit)
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<DrawSet<D>, A::Error> {
+		SeededDraws::deserialize(MapAccessDeserializer::new(map)).map(DrawSet::Seeded)
+	}
+}
+
+impl<D: UniformDraw + Clone> DrawSet<D> {
+	pub fn draws(&self) -> Result<Cow<'_, [D]>, PricingError> {
+		let draws = match *self {
+			DrawSet::Explicit(ref draws) => Cow::Borrowed(&draws[..]),
+			DrawSet::Seeded(ref seeded) => Cow::Owned(seeded.generate()?)
+		};
+
+		return Ok(draws)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ::types::class_types::Draw;
+
+	#[test]
+	fn malformed_explicit_draws_name_the_field() {
+		let json = r#"[{"month1ClassiiiPriceDraw": 0.5, "month2ClassiiiPriceDraw": 0.5, "month3ClassiiiPriceDraw": 0.5,
+			"month1ClassivPriceDraw": 0.5, "month2ClassivPriceDraw": 0.5, "month3ClassivPriceDraw": "0.5", "yieldDrawQuantity": 0.5}]"#;
+
+		let err = serde_json::from_str::<DrawSet<Draw>>(json).err().unwrap().to_string();
+		assert!(err.contains("invalid type: string \"0.5\", expected f64"), "{}", err);
+	}
+
+	#[test]
+	fn reads_explicit_and_seeded_draws() {
+		let json = r#"[{"month1ClassiiiPriceDraw": 0.5, "month2ClassiiiPriceDraw": 0.5, "month3ClassiiiPriceDraw": 0.5,
+			"month1ClassivPriceDraw": 0.5, "month2ClassivPriceDraw": 0.5, "month3ClassivPriceDraw": 0.5, "yieldDrawQuantity": 0.5}]"#;
+
+		assert_eq!(serde_json::from_str::<DrawSet<Draw>>(json).unwrap().draws().unwrap().len(), 1);
+		assert_eq!(serde_json::from_str::<DrawSet<Draw>>(r#"{"seed": 3, "count": 25}"#).unwrap().draws().unwrap().len(), 25);
+
+		let err = serde_json::from_str::<DrawSet<Draw>>(r#"{"seed": 3}"#).err().unwrap().to_string();
+		assert!(err.contains("missing field `count`"), "{}", err);
+	}
+}
@@ -0,0 +1,243 @@
+use ::common::helpers::default_coverage_levels;
+use ::common::random::UniformDraw;
+use ::types::base_types::{ConvergenceSettings, DrawSet, EngineConfig, Quote, TraceFormat};
+
+#[derive(Deserialize)]
+pub struct Input {
+    pub draws: DrawSet<Draw>,
+    pub prices: DailyPrice,
+    pub quote: Quote,
+    pub coverage: f64,
+
+    // Levels priced by the multi-endorsement calls
+    #[serde(rename = "coverageLevels", default = "default_coverage_levels")]
+    pub coverage_levels: Vec<f64>,
+
+    #[serde(default)]
+    pub config: EngineConfig
+}
+
+// Everything a pricing session needs up front; quotes are priced against it later
+#[derive(Deserialize)]
+pub struct SessionInput {
+    pub draws: DrawSet<Draw>,
+    pub prices: DailyPrice,
+
+    #[serde(default)]
+    pub config: EngineConfig
+}
+
+// One day's prices and draws shared by many quotes
+#[derive(Deserialize)]
+pub struct BatchInput {
+    pub draws: DrawSet<Draw>,
+    pub prices: DailyPrice,
+    pub quotes: Vec<Quote>,
+
+    // Levels priced for every quote
+    #[serde(rename = "coverageLevels", default = "default_coverage_levels")]
+    pub coverage_levels: Vec<f64>,
+
+    #[serde(default)]
+    pub config: EngineConfig
+}
+
+// Every draw's simulation for one coverage level, returned or written to `path`
+#[derive(Deserialize)]
+pub struct TraceInput {
+    pub draws: DrawSet<Draw>,
+    pub prices: DailyPrice,
+    pub quote: Quote,
+    pub coverage: f64,
+    pub format: TraceFormat,
+
+    #[serde(default)]
+    pub path: Option<String>,
+
+    #[serde(default)]
+    pub config: EngineConfig
+}
+
+// Premium from pseudo-random and Sobol draw sets of increasing size
+#[derive(Deserialize)]
+pub struct ConvergenceInput {
+    pub prices: DailyPrice,
+    pub quote: Quote,
+    pub coverage: f64,
+    pub convergence: ConvergenceSettings,
+
+    #[serde(default)]
+    pub config: EngineConfig
+}
+
+// Announced prices after the quarter closes, priced against the same quote
+#[derive(Deserialize)]
+pub struct SettlementInput {
+    pub actual: ActualPrice,
+    pub prices: DailyPrice,
+    pub quote: Quote,
+
+    #[serde(rename = "coverageLevels", default = "default_coverage_levels")]
+    pub coverage_levels: Vec<f64>,
+
+    #[serde(default)]
+    pub config: EngineConfig
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Draw {
+    
+    #[serde(rename = "month1ClassiiiPriceDraw")]
+    pub m1c3pd: f64,
+
+    #[serde(rename = "month2ClassiiiPriceDraw")]
+    pub m2c3pd: f64,
+
+    #[serde(rename = "month3ClassiiiPriceDraw")]
+    pub m3c3pd: f64,
+
+    #[serde(rename = "month1ClassivPriceDraw")]
+    pub m1c4pd: f64,
+
+    #[serde(rename = "month2ClassivPriceDraw")]
+    pub m2c4pd: f64,
+
+    #[serde(rename = "month3ClassivPriceDraw")]
+    pub m3c4pd: f64,
+
+    #[serde(rename = "yieldDrawQuantity")]
+    pub ydq: f64
+}
+
+impl UniformDraw for Draw {
+    const DIMENSION: usize = 7;
+
+    fn from_uniforms(uniforms: &[f64]) -> Draw {
+        Draw {
+            m1c3pd: uniforms[0],
+            m2c3pd: uniforms[1],
+            m3c3pd: uniforms[2],
+            m1c4pd: uniforms[3],
+            m2c4pd: uniforms[4],
+            m3c4pd: uniforms[5],
+            ydq: uniforms[6]
+        }
+    }
+
+    fn antithetic(&self) -> Draw {
+        Draw {
+            m1c3pd: 1.0 - self.m1c3pd,
+            m2c3pd: 1.0 - self.m2c3pd,
+            m3c3pd: 1.0 - self.m3c3pd,
+            m1c4pd: 1.0 - self.m1c4pd,
+            m2c4pd: 1.0 - self.m2c4pd,
+            m3c4pd: 1.0 - self.m3c4pd,
+            ydq: 1.0 - self.ydq
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SimulationValue {
+    #[serde(rename = "simulatedClassThreePrice")]
+    pub sc3p: f64,
+
+    #[serde(rename = "simulatedClassFourPrice")]
+    pub sc4p: f64,
+
+    #[serde(rename = "simulatedYieldAdjustmentFactor")]
+    pub syaf: f64
+}
+
+#[derive(Deserialize, Copy, Clone)]
+pub struct DailyPrice {
+
+    #[serde(rename = "expectedClassThreePrice")]
+    pub ec3p: f64,
+
+    #[serde(rename = "expectedClassFourPrice")]
+    pub ec4p: f64,
+
+    #[serde(rename = "loadingFactor")]
+    pub lf: f64,
+
+    #[serde(rename = "monthOneClassThreeSigma")]
+    pub m1c3s: f64,
+
+    #[serde(rename = "monthOneExpectedClassThreePrice")]
+    pub m1ec3p: f64,
+
+    #[serde(rename = "monthTwoClassThreeSigma")]
+    pub m2c3s: f64,
+
+    #[serde(rename = "monthTwoExpectedClassThreePrice")]
+    pub m2ec3p: f64,
+
+    #[serde(rename = "monthThreeClassThreeSigma")]
+    pub m3c3s: f64,
+
+    #[serde(rename = "monthThreeExpectedClassThreePrice")]
+    pub m3ec3p: f64,
+
+    #[serde(rename = "monthOneClassFourSigma")]
+    pub m1c4s: f64,
+
+    #[serde(rename = "monthOneExpectedClassFourPrice")]
+    pub m1ec4p: f64,
+
+    #[serde(rename = "monthTwoClassFourSigma")]
+    pub m2c4s: f64,
+
+    #[serde(rename = "monthTwoExpectedClassFourPrice")]
+    pub m2ec4p: f64,
+
+    #[serde(rename = "monthThreeClassFourSigma")]
+    pub m3c4s: f64,
+
+    #[serde(rename = "monthThreeExpectedClassFourPrice")]
+    pub m3ec4p: f64,
+
+    #[serde(rename = "expectedYield")]
+    pub ey: f64,
+
+    #[serde(rename = "expectedYieldStandardDeviation")]
+    pub eysd: f64,
+
+    // Announced prices for months that have already settled, used in place of the draws
+    #[serde(rename = "monthOneClassThreePrice", default)]
+    pub m1c3p: Option<f64>,
+
+    #[serde(rename = "monthTwoClassThreePrice", default)]
+    pub m2c3p: Option<f64>,
+
+    #[serde(rename = "monthOneClassFourPrice", default)]
+    pub m1c4p: Option<f64>,
+
+    #[serde(rename = "monthTwoClassFourPrice", default)]
+    pub m2c4p: Option<f64>
+}
+
+#[derive(Deserialize, Copy, Clone)]
+pub struct ActualPrice {
+
+    #[serde(rename = "monthOneClassThreePrice")]
+    pub m1c3p: f64,
+
+    #[serde(rename = "monthTwoClassThreePrice")]
+    pub m2c3p: f64,
+
+    #[serde(rename = "monthThreeClassThreePrice")]
+    pub m3c3p: f64,
+
+    #[serde(rename = "monthOneClassFourPrice")]
+    pub m1c4p: f64,
+
+    #[serde(rename = "monthTwoClassFourPrice")]
+    pub m2c4p: f64,
+
+    #[serde(rename = "monthThreeClassFourPrice")]
+    pub m3c4p: f64,
+
+    #[serde(rename = "yieldAdjustmentFactor")]
+    pub yaf: f64
+}
@@ -0,0 +1,422 @@
+use ::common::helpers::default_coverage_levels;
+use ::common::random::UniformDraw;
+use ::types::base_types::{ConvergenceSettings, DrawSet, EngineConfig, Quote, TraceFormat};
+
+#[derive(Deserialize)]
+pub struct Input {
+	pub draws: DrawSet<Draw>,
+	pub factors: PricingFactor,
+	pub prices: DailyPrice,
+	pub quote: Quote,
+	pub coverage: f64,
+
+	// Levels priced by the multi-endorsement calls
+	#[serde(rename = "coverageLevels", default = "default_coverage_levels")]
+	pub coverage_levels: Vec<f64>,
+
+	#[serde(default)]
+	pub config: EngineConfig
+}
+
+// Everything a pricing session needs up front; quotes are priced against it later
+#[derive(Deserialize)]
+pub struct SessionInput {
+	pub draws: DrawSet<Draw>,
+	pub factors: PricingFactor,
+	pub prices: DailyPrice,
+
+	#[serde(default)]
+	pub config: EngineConfig
+}
+
+// One day's prices and draws shared by many quotes
+#[derive(Deserialize)]
+pub struct BatchInput {
+	pub draws: DrawSet<Draw>,
+	pub factors: PricingFactor,
+	pub prices: DailyPrice,
+	pub quotes: Vec<Quote>,
+
+	// Levels priced for every quote
+	#[serde(rename = "coverageLevels", default = "default_coverage_levels")]
+	pub coverage_levels: Vec<f64>,
+
+	#[serde(default)]
+	pub config: EngineConfig
+}
+
+// Every draw's simulation for one coverage level, returned or written to `path`
+#[derive(Deserialize)]
+pub struct TraceInput {
+	pub draws: DrawSet<Draw>,
+	pub factors: PricingFactor,
+	pub prices: DailyPrice,
+	pub quote: Quote,
+	pub coverage: f64,
+	pub format: TraceFormat,
+
+	#[serde(default)]
+	pub path: Option<String>,
+
+	#[serde(default)]
+	pub config: EngineConfig
+}
+
+// Premium from pseudo-random and Sobol draw sets of increasing size
+#[derive(Deserialize)]
+pub struct ConvergenceInput {
+	pub factors: PricingFactor,
+	pub prices: DailyPrice,
+	pub quote: Quote,
+	pub coverage: f64,
+	pub convergence: ConvergenceSettings,
+
+	#[serde(default)]
+	pub config: EngineConfig
+}
+
+// Announced prices after the quarter closes, priced against the same quote
+#[derive(Deserialize)]
+pub struct SettlementInput {
+	pub actual: ActualPrice,
+	pub factors: PricingFactor,
+	pub prices: DailyPrice,
+	pub quote: Quote,
+
+	#[serde(rename = "coverageLevels", default = "default_coverage_levels")]
+	pub coverage_levels: Vec<f64>,
+
+	#[serde(default)]
+	pub config: EngineConfig
+}
+
+#[derive(Deserialize)]
+pub struct ComponentPriceInput {
+	pub factors: PricingFactor,
+	pub products: ProductPrice,
+
+	#[serde(default)]
+	pub config: EngineConfig
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Draw {
+	#[serde(rename = "month1ButterPriceDraw")]
+	pub m1bpd: f64,
+
+	#[serde(rename = "month2ButterPriceDraw")]
+	pub m2bpd: f64,
+
+	#[serde(rename = "month3ButterPriceDraw")]
+	pub m3bpd: f64,
+
+	#[serde(rename = "month1CheesePriceDraw")]
+	pub m1cpd: f64,
+
+	#[serde(rename = "month2CheesePriceDraw")]
+	pub m2cpd: f64,
+
+	#[serde(rename = "month3CheesePriceDraw")]
+	pub m3cpd: f64,
+
+	#[serde(rename = "month1DryWheyPriceDraw")]
+	pub m1dwpd: f64,
+
+	#[serde(rename = "month2DryWheyPriceDraw")]
+	pub m2dwpd: f64,
+
+	#[serde(rename = "month3DryWheyPriceDraw")]
+	pub m3dwpd: f64,
+
+	#[serde(rename = "yieldDrawQuantity")]
+	pub ydq: f64
+}
+
+impl UniformDraw for Draw {
+	const DIMENSION: usize = 10;
+
+	fn from_uniforms(uniforms: &[f64]) -> Draw {
+		Draw {
+			m1bpd: uniforms[0],
+			m2bpd: uniforms[1],
+			m3bpd: uniforms[2],
+			m1cpd: uniforms[3],
+			m2cpd: uniforms[4],
+			m3cpd: uniforms[5],
+			m1dwpd: uniforms[6],
+			m2dwpd: uniforms[7],
+			m3dwpd: uniforms[8],
+			ydq: uniforms[9]
+		}
+	}
+
+	fn antithetic(&self) -> Draw {
+		Draw {
+			m1bpd: 1.0 - self.m1bpd,
+			m2bpd: 1.0 - self.m2bpd,
+			m3bpd: 1.0 - self.m3bpd,
+			m1cpd: 1.0 - self.m1cpd,
+			m2cpd: 1.0 - self.m2cpd,
+			m3cpd: 1.0 - self.m3cpd,
+			m1dwpd: 1.0 - self.m1dwpd,
+			m2dwpd: 1.0 - self.m2dwpd,
+			m3dwpd: 1.0 - self.m3dwpd,
+			ydq: 1.0 - self.ydq
+		}
+	}
+}
+
+#[derive(Copy, Clone)]
+pub struct SimulationValue {
+    pub sbfp: f64,
+    pub spp: f64,
+    pub sosp: f64,
+    pub syaf: f64
+}
+
+#[derive(Deserialize, Copy, Clone)]
+pub struct DailyPrice {
+
+	#[serde(rename = "expectedButterfatPrice")]
+	pub ebfp: f64,
+
+	#[serde(rename = "expectedProteinPrice")]
+	pub epp: f64,
+
+	#[serde(rename = "expectedOtherSolidsPrice")]
+	pub eosp: f64,
+	
+	#[serde(rename = "monthOneButterSigma")]
+	pub m1bs: f64,
+
+	#[serde(rename = "monthOneExpectedButterPrice")]
+	pub m1ebp: f64,
+
+	#[serde(rename = "monthTwoButterSigma")]
+	pub m2bs: f64,
+
+	#[serde(rename = "monthTwoExpectedButterPrice")]
+	pub m2ebp: f64,
+
+	#[serde(rename = "monthThreeButterSigma")]
+	pub m3bs: f64,
+
+	#[serde(rename = "monthThreeExpectedButterPrice")]
+	pub m3ebp: f64,
+
+	#[serde(rename = "monthOneCheeseSigma")]
+	pub m1cs: f64,
+
+	#[serde(rename = "monthOneExpectedCheesePrice")]
+	pub m1ecp: f64,
+
+	#[serde(rename = "monthTwoCheeseSigma")]
+	pub m2cs: f64,
+
+	#[serde(rename = "monthTwoExpectedCheesePrice")]
+	pub m2ecp: f64,
+
+	#[serde(rename = "monthThreeCheeseSigma")]
+	pub m3cs: f64,
+
+	#[serde(rename = "monthThreeExpectedCheesePrice")]
+	pub m3ecp: f64,
+
+	#[serde(rename = "monthOneDryWheySigma")]
+	pub m1dws: f64,
+
+	#[serde(rename = "monthOneExpectedDryWheyPrice")]
+	pub m1edwp: f64,
+
+	#[serde(rename = "monthTwoDryWheySigma")]
+	pub m2dws: f64,
+
+	#[serde(rename = "monthTwoExpectedDryWheyPrice")]
+	pub m2edwp: f64,
+
+	#[serde(rename = "monthThreeDryWheySigma")]
+	pub m3dws: f64,
+
+	#[serde(rename = "monthThreeExpectedDryWheyPrice")]
+	pub m3edwp: f64,
+
+	#[serde(rename = "expectedYield")]
+    pub ey: f64,
+
+    #[serde(rename = "expectedYieldStandardDeviation")]
+    pub eysd: f64,
+
+    #[serde(rename = "loadingFactor")]
+    pub lf: f64,
+
+	// Announced prices for months that have already settled, used in place of the draws
+	#[serde(rename = "monthOneButterPrice", default)]
+	pub m1bp: Option<f64>,
+
+	#[serde(rename = "monthTwoButterPrice", default)]
+	pub m2bp: Option<f64>,
+
+	#[serde(rename = "monthOneCheesePrice", default)]
+	pub m1cp: Option<f64>,
+
+	#[serde(rename = "monthTwoCheesePrice", default)]
+	pub m2cp: Option<f64>,
+
+	#[serde(rename = "monthOneDryWheyPrice", default)]
+	pub m1dwp: Option<f64>,
+
+	#[serde(rename = "monthTwoDryWheyPrice", default)]
+	pub m2dwp: Option<f64>
+}
+
+#[derive(Deserialize, Copy, Clone)]
+pub struct PricingFactor {
+	#[serde(rename = "butterMakeAllowance")]
+	pub bma: f64,
+
+	#[serde(rename = "butterManufacturingYield")]
+	pub bmy: f64,
+
+	#[serde(rename = "dryWheyMakeAllowance")]
+	pub dwma: f64,
+
+	#[serde(rename = "dryWheyManufacturingYield")]
+	pub dwmy: f64,
+
+	#[serde(rename = "cheeseMakeAllowance")]
+	pub cma: f64,
+
+	#[serde(rename = "cheeseManufacturingYieldCasein")]
+	pub cmyc: f64,
+
+	#[serde(rename = "cheeseManufacturingYieldButterfat")]
+	pub cmyb: f64,
+
+	#[serde(rename = "butterfatRetentionRate")]
+	pub brr: f64,
+
+	#[serde(rename = "butterfatToProteinRatio")]
+	pub btpr: f64
+}
+
+// Announced monthly product prices, $/lb
+#[derive(Deserialize, Copy, Clone)]
+pub struct ProductPrice {
+	#[serde(rename = "monthOneButterPrice")]
+	pub m1bp: f64,
+
+	#[serde(rename = "monthTwoButterPrice")]
+	pub m2bp: f64,
+
+	#[serde(rename = "monthThreeButterPrice")]
+	pub m3bp: f64,
+
+	#[serde(rename = "monthOneCheesePrice")]
+	pub m1cp: f64,
+
+	#[serde(rename = "monthTwoCheesePrice")]
+	pub m2cp: f64,
+
+	#[serde(rename = "monthThreeCheesePrice")]
+	pub m3cp: f64,
+
+	#[serde(rename = "monthOneDryWheyPrice")]
+	pub m1dwp: f64,
+
+	#[serde(rename = "monthTwoDryWheyPrice")]
+	pub m2dwp: f64,
+
+	#[serde(rename = "monthThreeDryWheyPrice")]
+	pub m3dwp: f64
+}
+
+#[derive(Serialize, Copy, Clone, Debug)]
+pub struct ComponentPrice {
+	#[serde(rename = "monthOneButterfatPrice")]
+	pub m1bfp: f64,
+
+	#[serde(rename = "monthTwoButterfatPrice")]
+	pub m2bfp: f64,
+
+	#[serde(rename = "monthThreeButterfatPrice")]
+	pub m3bfp: f64,
+
+	#[serde(rename = "monthOneProteinPrice")]
+	pub m1pp: f64,
+
+	#[serde(rename = "monthTwoProteinPrice")]
+	pub m2pp: f64,
+
+	#[serde(rename = "monthThreeProteinPrice")]
+	pub m3pp: f64,
+
+	#[serde(rename = "monthOneOtherSolidsPrice")]
+	pub m1osp: f64,
+
+	#[serde(rename = "monthTwoOtherSolidsPrice")]
+	pub m2osp: f64,
+
+	#[serde(rename = "monthThreeOtherSolidsPrice")]
+	pub m3osp: f64,
+
+	// Quarterly averages
+	#[serde(rename = "butterfatPrice")]
+	pub bfp: f64,
+
+	#[serde(rename = "proteinPrice")]
+	pub pp: f64,
+
+	#[serde(rename = "otherSolidsPrice")]
+	pub osp: f64
+}
+
+impl ComponentPrice {
+	// The announced quarter in the shape settlement expects
+	pub fn actual_price(&self, yaf: f64) -> ActualPrice {
+		ActualPrice {
+			m1bfp: self.m1bfp,
+			m2bfp: self.m2bfp,
+			m3bfp: self.m3bfp,
+			m1pp: self.m1pp,
+			m2pp: self.m2pp,
+			m3pp: self.m3pp,
+			m1osp: self.m1osp,
+			m2osp: self.m2osp,
+			m3osp: self.m3osp,
+			yaf
+		}
+	}
+}
+
+#[derive(Deserialize, Copy, Clone)]
+pub struct ActualPrice {
+	#[serde(rename = "monthOneButterfatPrice")]
+	pub m1bfp: f64,
+
+	#[serde(rename = "monthTwoButterfatPrice")]
+	pub m2bfp: f64,
+
+	#[serde(rename = "monthThreeButterfatPrice")]
+	pub m3bfp: f64,
+
+	#[serde(rename = "monthOneProteinPrice")]
+	pub m1pp: f64,
+
+	#[serde(rename = "monthTwoProteinPrice")]
+	pub m2pp: f64,
+
+	#[serde(rename = "monthThreeProteinPrice")]
+	pub m3pp: f64,
+
+	#[serde(rename = "monthOneOtherSolidsPrice")]
+	pub m1osp: f64,
+
+	#[serde(rename = "monthTwoOtherSolidsPrice")]
+	pub m2osp: f64,
+
+	#[serde(rename = "monthThreeOtherSolidsPrice")]
+	pub m3osp: f64,
+
+	#[serde(rename = "yieldAdjustmentFactor")]
+	pub yaf: f64
+}
@@ -0,0 +1,146 @@
+use serde_json;
+
+use ::common::helpers::validate_coverage_level;
+use ::errors::PricingError;
+use ::types::base_types::Quote;
+
+#[derive(Deserialize, Serialize, Copy, Clone, Debug)]
+pub struct SubsidyRate {
+	#[serde(rename = "coverageLevel")]
+	pub level: f64,
+
+	#[serde(rename = "subsidyPercent")]
+	pub percent: f64
+}
+
+// Premium subsidy rates in effect from `crop_year` until a later schedule replaces them
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SubsidySchedule {
+	#[serde(rename = "cropYear")]
+	pub crop_year: u16,
+
+	pub rates: Vec<SubsidyRate>,
+
+	// Additional subsidy, in percentage points, for beginning or veteran farmers
+	#[serde(rename = "beginningVeteranPoints", default = "default_beginning_veteran_points")]
+	pub beginning_veteran_points: f64
+}
+
+fn default_beginning_veteran_points() -> f64 {
+	0.10
+}
+
+impl SubsidySchedule {
+	pub fn subsidy_percent(&self, coverage_level: f64) -> Result<f64, PricingError> {
+		validate_coverage_level(coverage_level)?;
+
+		let rate = self.rates.iter()
+			.find(|rate| (rate.level - coverage_level).abs() < 1e-9)
+			.ok_or_else(|| PricingError::InvalidSubsidySchedule(format!("crop year {} has no rate for coverage level {}", self.crop_year, coverage_level)))?;
+
+		if !(0.0..=1.0).contains(&rate.percent) {
+			return Err(PricingError::InvalidSubsidySchedule(format!("crop year {} has subsidy percent {} outside 0 to 1", self.crop_year, rate.percent)))
+		}
+
+		return Ok(rate.percent)
+	}
+
+	// Points added on top of the base rate; a producer who is both gets them once
+	pub fn enhanced_subsidy_points(&self, quote: &Quote) -> Result<f64, PricingError> {
+		if !(quote.bfr || quote.vfr) {
+			return Ok(0.0)
+		}
+
+		if !(0.0..=1.0).contains(&self.beginning_veteran_points) {
+			return Err(PricingError::InvalidSubsidySchedule(format!("crop year {} has beginning or veteran points {} outside 0 to 1", self.crop_year, self.beginning_veteran_points)))
+		}
+
+		return Ok(self.beginning_veteran_points)
+	}
+}
+
+// Every known schedule, looked up by the crop year being priced
+#[derive(Deserialize, Serialize, Clone, Debug)]
+#[serde(transparent)]
+pub struct SubsidyTable {
+	pub schedules: Vec<SubsidySchedule>
+}
+
+impl SubsidyTable {
+	pub fn from_json(json: &str) -> Result<SubsidyTable, PricingError> {
+		serde_json::from_str(json).map_err(|err| PricingError::InvalidSubsidySchedule(err.to_string()))
+	}
+
+	// The latest schedule that starts on or before `crop_year`, or the latest one overall
+	pub fn schedule_for(&self, crop_year: Option<u16>) -> Result<&SubsidySchedule, PricingError> {
+		let schedule = match crop_year {
+			Some(year) => self.schedules.iter()
+				.filter(|schedule| schedule.crop_year <= year)
+				.max_by_key(|schedule| schedule.crop_year)
+				.ok_or(PricingError::UnknownCropYear(year))?,
+			None => self.schedules.iter()
+				.max_by_key(|schedule| schedule.crop_year)
+				.ok_or_else(|| PricingError::InvalidSubsidySchedule("the subsidy table is empty".to_string()))?
+		};
+
+		return Ok(schedule)
+	}
+}
+
+impl Default for SubsidyTable {
+	// The schedule the engine has always priced with
+	fn default() -> SubsidyTable {
+		SubsidyTable {
+			schedules: vec![SubsidySchedule {
+				crop_year: 2019,
+				rates: vec![
+					SubsidyRate { level: 0.70, percent: 0.590 },
+					SubsidyRate { level: 0.75, percent: 0.550 },
+					SubsidyRate { level: 0.80, percent: 0.550 },
+					SubsidyRate { level: 0.85, percent: 0.490 },
+					SubsidyRate { level: 0.90, percent: 0.440 },
+					SubsidyRate { level: 0.95, percent: 0.440 }
+				],
+				beginning_veteran_points: default_beginning_veteran_points()
+			}]
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn table() -> SubsidyTable {
+		let mut later = SubsidyTable::default().schedules[0].clone();
+		later.crop_year = 2021;
+		later.rates[0].percent = 0.600;
+
+		let mut schedules = SubsidyTable::default().schedules;
+		schedules.push(later);
+
+		return SubsidyTable { schedules }
+	}
+
+	#[test]
+	fn picks_the_latest_schedule_in_effect() {
+		let table = table();
+
+		assert_eq!(table.schedule_for(Some(2018)).err(), Some(PricingError::UnknownCropYear(2018)));
+		assert_eq!(table.schedule_for(Some(2019)).unwrap().crop_year, 2019);
+		assert_eq!(table.schedule_for(Some(2020)).unwrap().crop_year, 2019);
+		assert_eq!(table.schedule_for(Some(2021)).unwrap().crop_year, 2021);
+		assert_eq!(table.schedule_for(Some(2030)).unwrap().crop_year, 2021);
+		assert_eq!(table.schedule_for(None).unwrap().crop_year, 2021);
+
+		assert_eq!(table.schedule_for(Some(2020)).unwrap().subsidy_percent(0.70), Ok(0.590));
+		assert_eq!(table.schedule_for(Some(2021)).unwrap().subsidy_percent(0.70), Ok(0.600));
+	}
+
+	#[test]
+	fn rejects_an_empty_table() {
+		let table = SubsidyTable::from_json("[]").unwrap();
+		assert_eq!(table.schedule_for(Some(2019)).err(), Some(PricingError::UnknownCropYear(2019)));
+		assert_eq!(table.schedule_for(None).err().map(|err| err.code()), Some("ERR_INVALID_SUBSIDY_SCHEDULE"));
+	}
+}
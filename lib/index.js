@@ -1,2 +1,34 @@
+var promisify = require('util').promisify;
 var addon = require('../native');
-console.log(addon.hello());
+
+module.exports = {
+	fetch_class_endorsements: addon.fetch_class_endorsements,
+	fetch_class_endorsement: addon.fetch_class_endorsement,
+	fetch_component_endorsements: addon.fetch_component_endorsements,
+	fetch_component_endorsement: addon.fetch_component_endorsement,
+	fetch_class_endorsements_batch: addon.fetch_class_endorsements_batch,
+	fetch_component_endorsements_batch: addon.fetch_component_endorsements_batch,
+	settle_class_endorsements: addon.settle_class_endorsements,
+	settle_component_endorsements: addon.settle_component_endorsements,
+	project_class_endorsements: addon.project_class_endorsements,
+	project_component_endorsements: addon.project_component_endorsements,
+	compare_class_convergence: addon.compare_class_convergence,
+	compare_component_convergence: addon.compare_component_convergence,
+	fetch_component_prices: addon.fetch_component_prices,
+
+	// CSV or JSON lines, returned as a string or written to `path`
+	trace_class_simulations: addon.trace_class_simulations,
+	trace_component_simulations: addon.trace_component_simulations,
+
+	// Run on the libuv thread pool and resolve with the same Output shape
+	fetch_class_endorsements_async: promisify(addon.fetch_class_endorsements_async),
+	fetch_class_endorsement_async: promisify(addon.fetch_class_endorsement_async),
+	fetch_component_endorsements_async: promisify(addon.fetch_component_endorsements_async),
+	fetch_component_endorsement_async: promisify(addon.fetch_component_endorsement_async),
+	fetch_class_endorsements_batch_async: promisify(addon.fetch_class_endorsements_batch_async),
+	fetch_component_endorsements_batch_async: promisify(addon.fetch_component_endorsements_batch_async),
+
+	// Built once from the day's prices and draws, then priced per quote
+	ClassSession: addon.ClassSession,
+	ComponentSession: addon.ComponentSession
+};
@@ -1 +0,0 @@
-pub mod helpers;
\ No newline at end of file
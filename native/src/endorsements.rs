@@ -1,2 +0,0 @@
-pub mod class_endorsement;
-pub mod component_endorsement;
\ No newline at end of file
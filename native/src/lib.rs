@@ -1,79 +1,358 @@
 // #[macro_use]
 extern crate neon;
 
-#[macro_use]
-extern crate serde_derive;
-
-#[macro_use]
 extern crate neon_serde;
+extern crate pricing_engine;
+extern crate serde;
 
-extern crate serde_json;
-
+use neon::object::This;
 use neon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::time::{Instant};
 
-mod common;
-mod types;
-mod endorsements;
+use pricing_engine::{ClassOption, ClassSession, ComponentOption, ComponentSession, ConvergencePoint, Endorsement, Output, PricingError, Projection, Quote, Settlement, DEFAULT_COVERAGE_LEVELS};
+use pricing_engine::component_types;
+use pricing_engine::class_types;
+
+
+fn fetch_class_endorsements(e: &class_types::Input) -> Result<Output, PricingError> {
+	let run_time = Instant::now();
+	let endorsements = pricing_engine::price_class(e.prices, e.quote, &e.draws.draws()?, &e.coverage_levels, &e.config)?;
+	let elapsed = run_time.elapsed();
+	let ms = elapsed.subsec_millis();
+	let ns = elapsed.subsec_nanos();
+
+	Ok(Output {
+		endorsements,
+		performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns),
+		antithetic: e.config.antithetic
+	})
+}
+
+fn fetch_class_endorsement(e: &class_types::Input) -> Result<Output, PricingError> {
+	let run_time = Instant::now();
+	let endorsement = pricing_engine::price_class_endorsement(e.prices, e.quote, &e.draws.draws()?, e.coverage, &e.config)?;
+	let elapsed = run_time.elapsed();
+	let ms = elapsed.subsec_millis();
+	let ns = elapsed.subsec_nanos();
+
+	Ok(Output {
+		endorsements: vec![endorsement],
+		performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns),
+		antithetic: e.config.antithetic
+	})
+}
+
+fn fetch_component_endorsements(e: &component_types::Input) -> Result<Output, PricingError> {
+	let run_time = Instant::now();
+	let endorsements = pricing_engine::price_component(e.prices, e.quote, &e.draws.draws()?, e.factors, &e.coverage_levels, &e.config)?;
+	let elapsed = run_time.elapsed();
+	let ms = elapsed.subsec_millis();
+	let ns = elapsed.subsec_nanos();
+
+	Ok(Output {
+		endorsements,
+		performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns),
+		antithetic: e.config.antithetic
+	})
+}
+
+fn fetch_component_endorsement(e: &component_types::Input) -> Result<Output, PricingError> {
+	let run_time = Instant::now();
+	let endorsement = pricing_engine::price_component_endorsement(e.prices, e.quote, &e.draws.draws()?, e.factors, e.coverage, &e.config)?;
+	let elapsed = run_time.elapsed();
+	let ms = elapsed.subsec_millis();
+	let ns = elapsed.subsec_nanos();
+
+	Ok(Output {
+		endorsements: vec![endorsement],
+		performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns),
+		antithetic: e.config.antithetic
+	})
+}
 
-use types::base_types::{Output};
-use types::component_types;
-use types::class_types;
+// Simulates the draws once and prices every quote against them
+fn fetch_class_endorsements_batch(e: &class_types::BatchInput) -> Result<Vec<Output>, PricingError> {
+	let session = ClassSession::new(ClassOption { prices: e.prices }, &e.draws.draws()?, e.config.clone())?;
 
-use endorsements::class_endorsement;
-use endorsements::component_endorsement;
+	e.quotes.iter().map(|quote| {
+		let run_time = Instant::now();
+		let endorsements = session.price_all(*quote, &e.coverage_levels)?;
+		Ok(timed_output(endorsements, e.config.antithetic, run_time))
+	}).collect()
+}
 
+fn fetch_component_endorsements_batch(e: &component_types::BatchInput) -> Result<Vec<Output>, PricingError> {
+	let session = ComponentSession::new(ComponentOption { prices: e.prices, factors: e.factors }, &e.draws.draws()?, e.config.clone())?;
 
-export! {
-	fn fetch_class_endorsements(e: class_types::Input) -> Output {
+	e.quotes.iter().map(|quote| {
 		let run_time = Instant::now();
-		let endorsements = class_endorsement::get_all_endorsements(e.prices, e.quote, e.draws);
-		let elapsed = run_time.elapsed();
-    	let ms = elapsed.subsec_millis();
-    	let ns = elapsed.subsec_nanos();
-
-		Output {
-			endorsements: endorsements,
-			performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns)
+		let endorsements = session.price_all(*quote, &e.coverage_levels)?;
+		Ok(timed_output(endorsements, e.config.antithetic, run_time))
+	}).collect()
+}
+
+// Indemnity owed once the quarter's prices are announced
+fn settle_class_endorsements(e: &class_types::SettlementInput) -> Result<Vec<Settlement>, PricingError> {
+	pricing_engine::settle_class(e.prices, e.quote, &e.actual, &e.coverage_levels, &e.config)
+}
+
+fn settle_component_endorsements(e: &component_types::SettlementInput) -> Result<Vec<Settlement>, PricingError> {
+	pricing_engine::settle_component(e.prices, e.quote, &e.actual, e.factors, &e.coverage_levels, &e.config)
+}
+
+// Expected indemnity with the announced months in the prices held fixed
+fn project_class_endorsements(e: &class_types::Input) -> Result<Vec<Projection>, PricingError> {
+	pricing_engine::project_class(e.prices, e.quote, &e.draws.draws()?, &e.coverage_levels, &e.config)
+}
+
+fn project_component_endorsements(e: &component_types::Input) -> Result<Vec<Projection>, PricingError> {
+	pricing_engine::project_component(e.prices, e.quote, &e.draws.draws()?, e.factors, &e.coverage_levels, &e.config)
+}
+
+// Gross premium spread from pseudo-random and Sobol draws at each draw count
+fn compare_class_convergence(e: &class_types::ConvergenceInput) -> Result<Vec<ConvergencePoint>, PricingError> {
+	pricing_engine::compare_class_convergence(e.prices, e.quote, e.coverage, &e.config, &e.convergence)
+}
+
+fn compare_component_convergence(e: &component_types::ConvergenceInput) -> Result<Vec<ConvergencePoint>, PricingError> {
+	pricing_engine::compare_component_convergence(e.prices, e.quote, e.factors, e.coverage, &e.config, &e.convergence)
+}
+
+// Returns the trace itself, or the path it was written to when one is given
+fn trace_class_simulations(e: &class_types::TraceInput) -> Result<String, PricingError> {
+	let draws = e.draws.draws()?;
+	trace_output(&e.path, |writer| pricing_engine::trace_class(e.prices, e.quote, &draws, e.coverage, e.format, &e.config, writer))
+}
+
+fn trace_component_simulations(e: &component_types::TraceInput) -> Result<String, PricingError> {
+	let draws = e.draws.draws()?;
+	trace_output(&e.path, |writer| pricing_engine::trace_component(e.prices, e.quote, &draws, e.factors, e.coverage, e.format, &e.config, writer))
+}
+
+fn trace_output<F>(path: &Option<String>, trace: F) -> Result<String, PricingError>
+	where F: FnOnce(&mut dyn Write) -> Result<usize, PricingError>
+{
+	match *path {
+		Some(ref path) => {
+			let file = File::create(path).map_err(|err| PricingError::TraceWriteFailed(err.to_string()))?;
+			trace(&mut BufWriter::new(file))?;
+			Ok(path.clone())
+		},
+		None => {
+			let mut buffer = Vec::new();
+			trace(&mut buffer)?;
+			Ok(String::from_utf8_lossy(&buffer).into_owned())
 		}
 	}
+}
 
-	fn fetch_class_endorsement(e: class_types::Input) -> Output {
-		let run_time = Instant::now();
-		let endorsement = class_endorsement::get_endorsement(e.prices, e.quote, e.draws, e.coverage);
-		let elapsed = run_time.elapsed();
-    	let ms = elapsed.subsec_millis();
-    	let ns = elapsed.subsec_nanos();
-
-		Output {
-			endorsements: vec![endorsement],
-			performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns)
+fn fetch_component_prices(e: &component_types::ComponentPriceInput) -> Result<component_types::ComponentPrice, PricingError> {
+	Ok(pricing_engine::component_prices(&e.products, &e.factors, &e.config))
+}
+
+fn timed_output(endorsements: Vec<Endorsement>, antithetic: bool, run_time: Instant) -> Output {
+	let elapsed = run_time.elapsed();
+	let ms = elapsed.subsec_millis();
+	let ns = elapsed.subsec_nanos();
+
+	Output {
+		endorsements,
+		performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns),
+		antithetic
+	}
+}
+
+// Throws a JS `Error` carrying the variant's stable `code` property
+fn throw_pricing_error<'a, C: Context<'a>, T>(cx: &mut C, err: PricingError) -> NeonResult<T> {
+	let error = JsError::error(cx, err.to_string())?;
+	let code = cx.string(err.code());
+	error.set(cx, "code", code)?;
+	cx.throw(error)
+}
+
+fn call_pricing<'a, I: DeserializeOwned, O: Serialize>(mut cx: FunctionContext<'a>, pricing: fn(&I) -> Result<O, PricingError>) -> JsResult<'a, JsValue> {
+	let arg = cx.argument::<JsValue>(0)?;
+	let input: I = neon_serde::from_value(&mut cx, arg)?;
+
+	match pricing(&input) {
+		Ok(output) => Ok(neon_serde::to_value(&mut cx, &output)?),
+		Err(err) => throw_pricing_error(&mut cx, err)
+	}
+}
+
+// Runs a pricing call on the libuv thread pool and hands the result to a node style callback
+struct PricingTask<I, O> {
+	input: I,
+	pricing: fn(&I) -> Result<O, PricingError>
+}
+
+impl<I: Send + 'static, O: Serialize + Send + 'static> Task for PricingTask<I, O> {
+	type Output = O;
+	type Error = PricingError;
+	type JsEvent = JsValue;
+
+	fn perform(&self) -> Result<O, PricingError> {
+		(self.pricing)(&self.input)
+	}
+
+	fn complete<'a>(self, mut cx: TaskContext<'a>, result: Result<O, PricingError>) -> JsResult<'a, JsValue> {
+		match result {
+			Ok(output) => Ok(neon_serde::to_value(&mut cx, &output)?),
+			Err(err) => throw_pricing_error(&mut cx, err)
 		}
 	}
+}
+
+fn schedule_pricing<'a, I, O>(mut cx: FunctionContext<'a>, pricing: fn(&I) -> Result<O, PricingError>) -> JsResult<'a, JsUndefined>
+	where I: DeserializeOwned + Send + 'static, O: Serialize + Send + 'static
+{
+	let arg = cx.argument::<JsValue>(0)?;
+	let callback = cx.argument::<JsFunction>(1)?;
+	let input: I = neon_serde::from_value(&mut cx, arg)?;
+
+	PricingTask { input, pricing }.schedule(callback);
+	Ok(cx.undefined())
+}
+
+fn session_output<'a, C: Context<'a>>(cx: &mut C, result: Result<Vec<Endorsement>, PricingError>, antithetic: bool, run_time: Instant) -> JsResult<'a, JsValue> {
+	match result {
+		Ok(endorsements) => Ok(neon_serde::to_value(cx, &timed_output(endorsements, antithetic, run_time))?),
+		Err(err) => throw_pricing_error(cx, err)
+	}
+}
 
-	fn fetch_component_endorsements(e: component_types::Input) -> Output {
-		let run_time = Instant::now();
-		let endorsements = component_endorsement::get_all_endorsements(e.prices,e.quote, e.draws,e.factors);
-		let elapsed = run_time.elapsed();
-    	let ms = elapsed.subsec_millis();
-    	let ns = elapsed.subsec_nanos();
-
-		Output {
-			endorsements: endorsements,
-			performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns)
+// Falls back to the default ladder when the argument is missing or undefined
+fn coverage_levels_argument<'a, T: This>(cx: &mut CallContext<'a, T>, i: i32) -> NeonResult<Vec<f64>> {
+	match cx.argument_opt(i) {
+		Some(arg) if !arg.is_a::<JsUndefined>() => Ok(neon_serde::from_value(cx, arg)?),
+		_ => Ok(DEFAULT_COVERAGE_LEVELS.to_vec())
+	}
+}
+
+// Sessions simulate the draws once on construction; `price` and `price_all` only run the loss loop
+declare_types! {
+	pub class JsClassSession for ClassSession {
+		init(mut cx) {
+			let arg = cx.argument::<JsValue>(0)?;
+			let input: class_types::SessionInput = neon_serde::from_value(&mut cx, arg)?;
+
+			let option = ClassOption { prices: input.prices };
+			let config = input.config;
+
+			match input.draws.draws().and_then(|draws| ClassSession::new(option, &draws, config)) {
+				Ok(session) => Ok(session),
+				Err(err) => throw_pricing_error(&mut cx, err)
+			}
+		}
+
+		method price(mut cx) {
+			let run_time = Instant::now();
+			let arg = cx.argument::<JsValue>(0)?;
+			let quote: Quote = neon_serde::from_value(&mut cx, arg)?;
+			let coverage_level = cx.argument::<JsNumber>(1)?.value();
+			let this = cx.this();
+
+			let (result, antithetic) = {
+				let guard = cx.lock();
+				let session = this.borrow(&guard);
+				(session.price(quote, coverage_level).map(|endorsement| vec![endorsement]), session.config().antithetic)
+			};
+
+			session_output(&mut cx, result, antithetic, run_time)
+		}
+
+		method price_all(mut cx) {
+			let run_time = Instant::now();
+			let arg = cx.argument::<JsValue>(0)?;
+			let quote: Quote = neon_serde::from_value(&mut cx, arg)?;
+			let coverage_levels = coverage_levels_argument(&mut cx, 1)?;
+			let this = cx.this();
+
+			let (result, antithetic) = {
+				let guard = cx.lock();
+				let session = this.borrow(&guard);
+				(session.price_all(quote, &coverage_levels), session.config().antithetic)
+			};
+
+			session_output(&mut cx, result, antithetic, run_time)
 		}
 	}
 
-	fn fetch_component_endorsement(e: component_types::Input) -> Output {
-		let run_time = Instant::now();
-		let endorsement = component_endorsement::get_endorsement(e.prices, e.quote, e.draws, e.factors, e.coverage);
-		let elapsed = run_time.elapsed();
-    	let ms = elapsed.subsec_millis();
-    	let ns = elapsed.subsec_nanos();
-
-		Output {
-			endorsements: vec![endorsement],
-			performance: format!("Runtime: {:?} | {}ms | {}ns", elapsed, ms, ns)
+	pub class JsComponentSession for ComponentSession {
+		init(mut cx) {
+			let arg = cx.argument::<JsValue>(0)?;
+			let input: component_types::SessionInput = neon_serde::from_value(&mut cx, arg)?;
+
+			let option = ComponentOption { prices: input.prices, factors: input.factors };
+			let config = input.config;
+
+			match input.draws.draws().and_then(|draws| ComponentSession::new(option, &draws, config)) {
+				Ok(session) => Ok(session),
+				Err(err) => throw_pricing_error(&mut cx, err)
+			}
+		}
+
+		method price(mut cx) {
+			let run_time = Instant::now();
+			let arg = cx.argument::<JsValue>(0)?;
+			let quote: Quote = neon_serde::from_value(&mut cx, arg)?;
+			let coverage_level = cx.argument::<JsNumber>(1)?.value();
+			let this = cx.this();
+
+			let (result, antithetic) = {
+				let guard = cx.lock();
+				let session = this.borrow(&guard);
+				(session.price(quote, coverage_level).map(|endorsement| vec![endorsement]), session.config().antithetic)
+			};
+
+			session_output(&mut cx, result, antithetic, run_time)
+		}
+
+		method price_all(mut cx) {
+			let run_time = Instant::now();
+			let arg = cx.argument::<JsValue>(0)?;
+			let quote: Quote = neon_serde::from_value(&mut cx, arg)?;
+			let coverage_levels = coverage_levels_argument(&mut cx, 1)?;
+			let this = cx.this();
+
+			let (result, antithetic) = {
+				let guard = cx.lock();
+				let session = this.borrow(&guard);
+				(session.price_all(quote, &coverage_levels), session.config().antithetic)
+			};
+
+			session_output(&mut cx, result, antithetic, run_time)
 		}
 	}
 }
+
+register_module!(mut m, {
+	m.export_function("fetch_class_endorsements", |cx| call_pricing(cx, fetch_class_endorsements))?;
+	m.export_function("fetch_class_endorsement", |cx| call_pricing(cx, fetch_class_endorsement))?;
+	m.export_function("fetch_component_endorsements", |cx| call_pricing(cx, fetch_component_endorsements))?;
+	m.export_function("fetch_component_endorsement", |cx| call_pricing(cx, fetch_component_endorsement))?;
+	m.export_function("fetch_class_endorsements_batch", |cx| call_pricing(cx, fetch_class_endorsements_batch))?;
+	m.export_function("fetch_component_endorsements_batch", |cx| call_pricing(cx, fetch_component_endorsements_batch))?;
+	m.export_function("settle_class_endorsements", |cx| call_pricing(cx, settle_class_endorsements))?;
+	m.export_function("settle_component_endorsements", |cx| call_pricing(cx, settle_component_endorsements))?;
+	m.export_function("project_class_endorsements", |cx| call_pricing(cx, project_class_endorsements))?;
+	m.export_function("project_component_endorsements", |cx| call_pricing(cx, project_component_endorsements))?;
+	m.export_function("compare_class_convergence", |cx| call_pricing(cx, compare_class_convergence))?;
+	m.export_function("compare_component_convergence", |cx| call_pricing(cx, compare_component_convergence))?;
+	m.export_function("trace_class_simulations", |cx| call_pricing(cx, trace_class_simulations))?;
+	m.export_function("trace_component_simulations", |cx| call_pricing(cx, trace_component_simulations))?;
+	m.export_function("fetch_component_prices", |cx| call_pricing(cx, fetch_component_prices))?;
+	m.export_function("fetch_class_endorsements_async", |cx| schedule_pricing(cx, fetch_class_endorsements))?;
+	m.export_function("fetch_class_endorsement_async", |cx| schedule_pricing(cx, fetch_class_endorsement))?;
+	m.export_function("fetch_component_endorsements_async", |cx| schedule_pricing(cx, fetch_component_endorsements))?;
+	m.export_function("fetch_component_endorsement_async", |cx| schedule_pricing(cx, fetch_component_endorsement))?;
+	m.export_function("fetch_class_endorsements_batch_async", |cx| schedule_pricing(cx, fetch_class_endorsements_batch))?;
+	m.export_function("fetch_component_endorsements_batch_async", |cx| schedule_pricing(cx, fetch_component_endorsements_batch))?;
+	m.export_class::<JsClassSession>("ClassSession")?;
+	m.export_class::<JsComponentSession>("ComponentSession")?;
+	Ok(())
+});
@@ -1,3 +0,0 @@
-pub mod base_types;
-pub mod class_types;
-pub mod component_types;
\ No newline at end of file